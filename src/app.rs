@@ -1,13 +1,19 @@
 use std::{sync::mpsc, time::Duration};
 
+use chrono::Local;
 use crossterm::{
-    event, execute,
+    event::{
+        self, DisableBracketedPaste, EnableBracketedPaste, KeyboardEnhancementFlags,
+        PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+    },
+    execute,
     terminal::{
         disable_raw_mode, enable_raw_mode, Clear, ClearType, EnterAlternateScreen,
         LeaveAlternateScreen,
     },
 };
 use ratatui::{prelude::CrosstermBackend, Terminal};
+use reqwest::Client;
 use tokio::{sync::watch, time};
 
 use crate::{
@@ -15,21 +21,35 @@ use crate::{
     common::enums::request::Request,
     context::Context,
     datasources,
-    entities::{configuration::Configuration, slack::conversations::Channel},
+    entities::{
+        configuration::Configuration,
+        slack::{authorization::Authorization, conversations::Channel, messages::PostResponse},
+    },
+    enums::connection::Connection,
     presentation::widgets,
     route,
+    utils::{self, sequence::Step},
 };
 
-pub async fn main(config: Configuration) {
+pub async fn main(config: Configuration, client: Client) {
     enable_raw_mode().unwrap();
 
     let (cmd_tx, cmd_rx) = mpsc::channel::<String>();
     let (req_tx, req_rx) = mpsc::channel::<Request>();
-    let (ctx_tx, ctx_rx) = watch::channel::<Context>(Context::default());
+    let mut context = Context::default();
+    context.state.command_line.history = datasources::history::get_history();
+
+    let (ctx_tx, ctx_rx) = watch::channel::<Context>(context);
 
     let cmd_process = tokio::spawn(cmd_thread(config.clone(), req_tx, ctx_tx.clone(), cmd_rx));
     let input_process = tokio::spawn(input_thread(config.clone(), cmd_tx.clone(), ctx_tx.clone()));
-    let request_process = tokio::spawn(request_thread(config.clone(), req_rx, ctx_tx, cmd_tx));
+    let request_process = tokio::spawn(request_thread(
+        config.clone(),
+        client,
+        req_rx,
+        ctx_tx,
+        cmd_tx,
+    ));
     let ui_process = tokio::spawn(ui_thread(config, ctx_rx));
 
     let _ = tokio::join!(cmd_process, input_process, request_process, ui_process);
@@ -43,13 +63,37 @@ async fn cmd_thread(
     ctx_tx: watch::Sender<Context>,
     cmd_rx: mpsc::Receiver<String>,
 ) {
+    let mut drafts = ctx_tx.borrow().state.input.drafts.clone();
+
     loop {
         let value = cmd_rx.recv().unwrap();
         let mut context = ctx_tx.borrow().clone();
+        let draft_key = context.state.input.draft_key.clone();
+        let starred = context.state.channel.starred.clone();
+        let history = context.state.command_line.history.clone();
 
-        let commands = route::get(context.current_route()).commands;
+        let commands = if value.starts_with(widgets::command_line::PREFIX) {
+            widgets::command_line::get().commands
+        } else {
+            route::get(context.current_route()).commands
+        };
+        let request = commands(&config, &value, &mut context);
+
+        // Drafts are written when leaving a conversation rather than on every keystroke
+        if (context.state.input.draft_key != draft_key || context.is_exit())
+            && context.state.input.drafts != drafts
+        {
+            drafts = context.state.input.drafts.clone();
+            let _ = datasources::drafts::store_drafts(&drafts);
+        }
+        if context.state.channel.starred != starred {
+            let _ = datasources::starred::store_starred(&context.state.channel.starred);
+        }
+        if context.state.command_line.history != history {
+            let _ = datasources::history::store_history(&context.state.command_line.history);
+        }
 
-        if let Some(request) = commands(&config, &value, &mut context) {
+        if let Some(request) = request {
             ctx_tx.send(context.clone()).unwrap();
             req_tx.send(request).unwrap();
         } else {
@@ -70,12 +114,45 @@ async fn input_thread(
     loop {
         let mut context = ctx_tx.borrow().clone();
 
+        if context.suspended {
+            time::sleep(Duration::from_millis(10)).await;
+            continue;
+        }
+
+        let screen = route::get(context.current_route());
+
+        if context.state.sequence.is_pending() {
+            let mut sequences = utils::sequence::bindings(&config, &context);
+            sequences.extend((screen.sequences)(&config, &context));
+            let command = utils::sequence::expire(&config, &mut context, &sequences);
+
+            if !context.state.sequence.is_pending() {
+                ctx_tx.send(context.clone()).unwrap();
+            }
+            if let Some(command) = command {
+                cmd_tx.send(command).unwrap();
+            }
+        }
+
         if event::poll(Duration::from_millis(10)).unwrap() {
             let event = event::read().unwrap();
 
-            let keymaps = route::get(context.current_route()).keymaps;
+            let mut sequences = utils::sequence::bindings(&config, &context);
+            sequences.extend((screen.sequences)(&config, &context));
+            let command_line =
+                (widgets::command_line::get().keymaps)(&config, &event, &mut context);
+            let command = if command_line.is_some() || context.state.command_line.is_open() {
+                command_line
+            } else {
+                match utils::sequence::step(&event, &mut context, &sequences) {
+                    Step::Command(command) => Some(command),
+                    Step::Consumed => None,
+                    Step::Unmatched(count) => (screen.keymaps)(&config, &event, &mut context)
+                        .map(|command| utils::sequence::with_count(&command, count)),
+                }
+            };
 
-            if let Some(command) = keymaps(&config, &event, &mut context) {
+            if let Some(command) = command {
                 ctx_tx.send(context.clone()).unwrap();
                 cmd_tx.send(command).unwrap();
             } else {
@@ -91,6 +168,7 @@ async fn input_thread(
 
 async fn request_thread(
     config: Configuration,
+    client: Client,
     req_rx: mpsc::Receiver<Request>,
     ctx_tx: watch::Sender<Context>,
     cmd_tx: mpsc::Sender<String>,
@@ -102,74 +180,321 @@ async fn request_thread(
             context.show_loading();
             ctx_tx.send(context.clone()).unwrap();
 
-            match value {
-                Request::Authorization(callback_command) => {
-                    command = Some(callback_command);
-                    let authorization = match datasources::slack::authorize_local().await {
-                        Ok(value) => value,
-                        Err(_) => {
-                            datasources::slack::authorize(
-                                config.slack.client_id.clone(),
-                                config.slack.client_secret.clone(),
+            let sync = !matches!(value, Request::OpenEditor(_));
+
+            let result: Result<(), Box<dyn std::error::Error + Send + Sync>> = async {
+                match value {
+                    Request::Authorization(callback_command) => {
+                        let (authorization, cached) =
+                            match datasources::slack::authorize_local().await {
+                                Ok(value) => (value, true),
+                                Err(_) => (
+                                    datasources::slack::authorize(
+                                        &client,
+                                        config.slack.client_id.clone(),
+                                        config.slack.client_secret.clone(),
+                                        config.slack.user_scopes.clone(),
+                                    )
+                                    .await?,
+                                    false,
+                                ),
+                            };
+
+                        let missing_scopes = if cached {
+                            utils::scope::missing(
+                                &authorization.authed_user.scope,
+                                &config.slack.user_scopes,
+                            )
+                        } else {
+                            Vec::new()
+                        };
+
+                        request_workspace(&config, &client, authorization, &ctx_tx).await?;
+
+                        if missing_scopes.is_empty() {
+                            command = Some(callback_command);
+                        }
+
+                        let mut context = ctx_tx.borrow().clone();
+                        context.state.global.missing_scopes = missing_scopes;
+                        ctx_tx.send(context).unwrap();
+                    }
+                    Request::Reauthorization(callback_command) => {
+                        command = Some(callback_command);
+                        let authorization = datasources::slack::authorize(
+                            &client,
+                            config.slack.client_id.clone(),
+                            config.slack.client_secret.clone(),
+                            config.slack.user_scopes.clone(),
+                        )
+                        .await?;
+
+                        request_workspace(&config, &client, authorization, &ctx_tx).await?;
+
+                        let mut context = ctx_tx.borrow().clone();
+                        context.state.global.missing_scopes = Vec::new();
+                        ctx_tx.send(context).unwrap();
+                    }
+                    Request::GetConversationHistory(channel_id) => {
+                        let messages = datasources::slack::get_conversations_history(
+                            &client,
+                            context.auth.clone().unwrap().authed_user.access_token,
+                            channel_id,
+                        )
+                        .await?;
+
+                        let mut context = ctx_tx.borrow().clone();
+                        context.state.message.messages = messages;
+                        ctx_tx.send(context).unwrap();
+                    }
+                    Request::GetConversationReplies => {
+                        let replies = datasources::slack::get_conversations_replies(
+                            &client,
+                            context.auth.clone().unwrap().authed_user.access_token,
+                            context.state.channel.opened.clone().unwrap().id,
+                            context.state.message.opened.clone().unwrap().ts,
+                        )
+                        .await?;
+
+                        let mut context = ctx_tx.borrow().clone();
+                        context.state.thread.messages = replies;
+                        ctx_tx.send(context).unwrap();
+                    }
+                    Request::OpenDirectMessage(user_id) => {
+                        let channel = datasources::slack::conversations_open(
+                            &client,
+                            context.auth.clone().unwrap().authed_user.access_token,
+                            user_id,
+                        )
+                        .await;
+
+                        if let Ok(channel) = channel {
+                            let mut context = ctx_tx.borrow().clone();
+                            if context
+                                .state
+                                .channel
+                                .get_channel(channel.id.clone())
+                                .is_none()
+                            {
+                                context
+                                    .state
+                                    .channel
+                                    .direct_messages
+                                    .insert(0, channel.clone());
+                            }
+                            ctx_tx.send(context).unwrap();
+
+                            command = Some(format!("switch {}", channel.id));
+                        }
+                    }
+                    Request::SendMessage(channel_id, text) => {
+                        let token = context.auth.clone().unwrap().authed_user.access_token;
+
+                        let response = datasources::slack::chat_post_message(
+                            &client,
+                            token.clone(),
+                            channel_id.clone(),
+                            utils::mention::encode(&text, &context.state),
+                        )
+                        .await;
+                        if !posted(&ctx_tx, response, &channel_id, &text)? {
+                            return Ok(());
+                        }
+
+                        let messages = datasources::slack::get_conversations_history(
+                            &client,
+                            token,
+                            channel_id.clone(),
+                        )
+                        .await?;
+
+                        let mut context = ctx_tx.borrow().clone();
+                        if context
+                            .state
+                            .channel
+                            .opened
+                            .as_ref()
+                            .map(|opened| &opened.id)
+                            == Some(&channel_id)
+                        {
+                            context.state.message.messages = messages;
+                        }
+                        ctx_tx.send(context).unwrap();
+                    }
+                    Request::SendReply(channel_id, ts, text, broadcast) => {
+                        let token = context.auth.clone().unwrap().authed_user.access_token;
+
+                        let response = datasources::slack::chat_post_message_reply(
+                            &client,
+                            token.clone(),
+                            channel_id.clone(),
+                            utils::mention::encode(&text, &context.state),
+                            ts.clone(),
+                            broadcast,
+                        )
+                        .await;
+                        let key = format!("{}.{}", channel_id, ts);
+                        if !posted(&ctx_tx, response, &key, &text)? {
+                            return Ok(());
+                        }
+
+                        let replies = datasources::slack::get_conversations_replies(
+                            &client,
+                            token.clone(),
+                            channel_id.clone(),
+                            ts.clone(),
+                        )
+                        .await?;
+
+                        let messages = if broadcast {
+                            Some(
+                                datasources::slack::get_conversations_history(
+                                    &client,
+                                    token,
+                                    channel_id.clone(),
+                                )
+                                .await?,
                             )
-                            .await?
+                        } else {
+                            None
+                        };
+
+                        let mut context = ctx_tx.borrow().clone();
+                        if context
+                            .state
+                            .message
+                            .opened
+                            .as_ref()
+                            .map(|opened| &opened.ts)
+                            == Some(&ts)
+                        {
+                            context.state.thread.messages = replies;
                         }
-                    };
-                    let token = authorization.authed_user.access_token.clone();
-                    let mut channels = datasources::slack::get_conversations(token.clone())
-                        .await?
-                        .iter()
-                        .filter(|channel| !channel.is_im && !channel.is_mpim.unwrap_or(false))
-                        .map(|channel| channel.to_owned())
-                        .collect::<Vec<Channel>>();
-                    channels.sort_by(|a, b| b.updated.unwrap_or(0).cmp(&a.updated.unwrap_or(0)));
-
-                    let mut direct_messages = datasources::slack::get_conversations(token.clone())
-                        .await?
-                        .iter()
-                        .filter(|channel| channel.is_im || channel.is_mpim.unwrap_or(false))
-                        .map(|channel| channel.to_owned())
-                        .collect::<Vec<Channel>>();
-                    direct_messages
-                        .sort_by(|a, b| b.updated.unwrap_or(0).cmp(&a.updated.unwrap_or(0)));
-
-                    let members = datasources::slack::get_users_list(token).await?;
-
-                    let mut context = ctx_tx.borrow().clone();
-                    context.auth = Some(authorization);
-                    context.state.channel.channels = channels;
-                    context.state.channel.direct_messages = direct_messages;
-                    context.state.global.members = members;
-
-                    ctx_tx.send(context).unwrap();
-                }
-                Request::GetConversationHistory(channel_id) => {
-                    let messages = datasources::slack::get_conversations_history(
-                        context.auth.clone().unwrap().authed_user.access_token,
-                        channel_id,
-                    )
-                    .await?;
-
-                    let mut context = ctx_tx.borrow().clone();
-                    context.state.message.messages = messages;
-                    ctx_tx.send(context).unwrap();
-                }
-                Request::GetConversationReplies => {
-                    let replies = datasources::slack::get_conversations_replies(
-                        context.auth.clone().unwrap().authed_user.access_token,
-                        context.state.channel.opened.clone().unwrap().id,
-                        context.state.message.opened.clone().unwrap().ts,
-                    )
-                    .await?;
-
-                    let mut context = ctx_tx.borrow().clone();
-                    context.state.thread.messages = replies;
-                    ctx_tx.send(context).unwrap();
+                        if let Some(messages) = messages {
+                            if context
+                                .state
+                                .channel
+                                .opened
+                                .as_ref()
+                                .map(|opened| &opened.id)
+                                == Some(&channel_id)
+                            {
+                                context.state.message.messages = messages;
+                            }
+                        }
+                        ctx_tx.send(context).unwrap();
+                    }
+                    Request::OpenEditor(text) => {
+                        if let Some(value) = external_edit(&ctx_tx, text).await {
+                            let mut context = ctx_tx.borrow().clone();
+                            context.state.input.editor.set(value);
+                            context.state.input.save_draft();
+                            let _ = datasources::drafts::store_drafts(&context.state.input.drafts);
+                            ctx_tx.send(context).unwrap();
+                        }
+                    }
+                    Request::EditMessage(channel_id, ts, text) => {
+                        let Some(value) = external_edit(&ctx_tx, text.clone()).await else {
+                            return Ok(());
+                        };
+                        if value.trim().is_empty() || value == text {
+                            return Ok(());
+                        }
+
+                        let token = context.auth.clone().unwrap().authed_user.access_token;
+
+                        let response = datasources::slack::chat_update(
+                            &client,
+                            token.clone(),
+                            channel_id.clone(),
+                            ts,
+                            utils::mention::encode(&value, &context.state),
+                        )
+                        .await?;
+
+                        if !response.ok {
+                            let mut context = ctx_tx.borrow().clone();
+                            context.state.global.error = Some(format!(
+                                "Message not edited: {}",
+                                response.error.unwrap_or(String::from("unknown error"))
+                            ));
+                            ctx_tx.send(context).unwrap();
+                            return Ok(());
+                        }
+
+                        let messages = datasources::slack::get_conversations_history(
+                            &client,
+                            token.clone(),
+                            channel_id.clone(),
+                        )
+                        .await?;
+
+                        let thread_ts = ctx_tx
+                            .borrow()
+                            .state
+                            .message
+                            .opened
+                            .as_ref()
+                            .map(|opened| opened.ts.clone());
+                        let replies = match thread_ts {
+                            Some(thread_ts) => Some((
+                                datasources::slack::get_conversations_replies(
+                                    &client,
+                                    token,
+                                    channel_id.clone(),
+                                    thread_ts.clone(),
+                                )
+                                .await?,
+                                thread_ts,
+                            )),
+                            None => None,
+                        };
+
+                        let mut context = ctx_tx.borrow().clone();
+                        if context
+                            .state
+                            .channel
+                            .opened
+                            .as_ref()
+                            .map(|opened| &opened.id)
+                            == Some(&channel_id)
+                        {
+                            context.state.message.messages = messages;
+                            context.state.message.refresh_selected();
+
+                            if let Some((replies, thread_ts)) = replies {
+                                if context
+                                    .state
+                                    .message
+                                    .opened
+                                    .as_ref()
+                                    .map(|opened| &opened.ts)
+                                    == Some(&thread_ts)
+                                {
+                                    context.state.thread.messages = replies;
+                                    context.state.thread.refresh_selected();
+                                }
+                            }
+                        }
+                        ctx_tx.send(context).unwrap();
+                    }
                 }
+                Ok(())
             }
+            .await;
 
             let mut context = ctx_tx.borrow().clone();
             context.hide_loading();
+            match result {
+                Ok(_) if sync => {
+                    context.state.global.connection = Connection::Online;
+                    context.state.global.last_sync = Some(Local::now().timestamp());
+                }
+                Ok(_) => {}
+                Err(_) => {
+                    context.state.global.connection = Connection::Offline;
+                }
+            }
             ctx_tx.send(context).unwrap();
 
             if let Some(cmd) = command {
@@ -186,9 +511,216 @@ async fn request_thread(
     Ok(())
 }
 
+async fn external_edit(ctx_tx: &watch::Sender<Context>, text: String) -> Option<String> {
+    let mut context = ctx_tx.borrow().clone();
+    context.suspend();
+    ctx_tx.send(context).unwrap();
+
+    time::sleep(Duration::from_millis(50)).await;
+
+    let result = match tokio::task::spawn_blocking(move || utils::editor::edit(&text)).await {
+        Ok(result) => result,
+        Err(error) => Err(error.into()),
+    };
+
+    let mut context = ctx_tx.borrow().clone();
+    context.resume();
+    if let Err(error) = &result {
+        context.state.global.error = Some(format!("Editor failed: {}", error));
+    }
+    ctx_tx.send(context).unwrap();
+
+    result.ok()
+}
+
+fn same_workspace(old_context: &Context, context: &Context) -> bool {
+    let (old, new) = (&old_context.state, &context.state);
+
+    old.global.members == new.global.members
+        && old.global.usergroups == new.global.usergroups
+        && old.global.emojis == new.global.emojis
+        && old
+            .channel
+            .channels
+            .iter()
+            .map(|channel| &channel.name)
+            .eq(new.channel.channels.iter().map(|channel| &channel.name))
+}
+
+fn posted(
+    ctx_tx: &watch::Sender<Context>,
+    response: Result<PostResponse, Box<dyn std::error::Error + Send + Sync>>,
+    key: &str,
+    text: &str,
+) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+    let mut context = ctx_tx.borrow().clone();
+
+    let error = match response {
+        Ok(response) if response.ok => {
+            context.state.input.sent(key, text);
+            let _ = datasources::drafts::store_drafts(&context.state.input.drafts);
+            ctx_tx.send(context).unwrap();
+            return Ok(true);
+        }
+        Ok(response) => response.error.unwrap_or(String::from("unknown error")),
+        Err(error) => {
+            context.state.global.error = Some(format!("Message not sent: {}", error));
+            ctx_tx.send(context).unwrap();
+            return Err(error);
+        }
+    };
+
+    context.state.global.error = Some(format!("Message not sent: {}", error));
+    ctx_tx.send(context).unwrap();
+
+    Ok(false)
+}
+
+async fn request_workspace(
+    config: &Configuration,
+    client: &Client,
+    authorization: Authorization,
+    ctx_tx: &watch::Sender<Context>,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let token = authorization.authed_user.access_token.clone();
+    let user_id = authorization.authed_user.id.clone();
+    let mut channels = datasources::slack::get_conversations(client, token.clone())
+        .await?
+        .iter()
+        .filter(|channel| !channel.is_im && !channel.is_mpim.unwrap_or(false))
+        .map(|channel| channel.to_owned())
+        .collect::<Vec<Channel>>();
+    channels.sort_by(|a, b| b.updated.unwrap_or(0).cmp(&a.updated.unwrap_or(0)));
+
+    let mut direct_messages = datasources::slack::get_conversations(client, token.clone())
+        .await?
+        .iter()
+        .filter(|channel| channel.is_im || channel.is_mpim.unwrap_or(false))
+        .map(|channel| channel.to_owned())
+        .collect::<Vec<Channel>>();
+    direct_messages.sort_by(|a, b| b.updated.unwrap_or(0).cmp(&a.updated.unwrap_or(0)));
+
+    let members = datasources::slack::get_users_list(client, token.clone()).await?;
+    let usergroups = datasources::slack::get_usergroups_list(client, token.clone())
+        .await
+        .unwrap_or_default();
+    let emojis = datasources::slack::get_emoji_list(client, token.clone())
+        .await
+        .unwrap_or_default();
+    let presence = datasources::slack::get_presence(client, token.clone())
+        .await
+        .ok();
+
+    let mut context = ctx_tx.borrow().clone();
+    context.auth = Some(authorization);
+    context.state.channel.channels = channels;
+    context.state.channel.direct_messages = direct_messages;
+    context.state.global.members = members;
+    context.state.global.usergroups = usergroups;
+    context.state.global.emojis = emojis;
+    context.state.global.presence = presence;
+    context.state.input.drafts = datasources::drafts::get_drafts();
+    context.state.channel.starred = datasources::starred::get_starred();
+
+    let muted = widgets::common::channel::muted(config);
+    let channel_ids: Vec<String> = context
+        .state
+        .channel
+        .channels
+        .iter()
+        .chain(context.state.channel.direct_messages.iter())
+        .filter(|channel| {
+            let title = widgets::common::channel::title(channel, &context);
+            !widgets::common::channel::matches(channel, &title, &muted)
+        })
+        .map(|channel| channel.id.clone())
+        .collect();
+
+    ctx_tx.send(context).unwrap();
+
+    tokio::spawn(request_unread_counts(
+        client.clone(),
+        token,
+        user_id,
+        channel_ids,
+        ctx_tx.clone(),
+    ));
+
+    Ok(())
+}
+
+// users.conversations has no unread counts, so they are filled in per conversation
+async fn request_unread_counts(
+    client: Client,
+    token: String,
+    user_id: String,
+    channel_ids: Vec<String>,
+    ctx_tx: watch::Sender<Context>,
+) {
+    for channel_id in channel_ids {
+        let response = loop {
+            match datasources::slack::conversations_info(&client, token.clone(), channel_id.clone())
+                .await
+            {
+                Ok(response) if response.error.as_deref() == Some("ratelimited") => {
+                    time::sleep(Duration::from_secs(30)).await;
+                }
+                response => break response,
+            }
+        };
+
+        let Some(info) = response.ok().and_then(|response| response.channel) else {
+            continue;
+        };
+        let count = info.unread_count_display.unwrap_or(0);
+
+        let direct = ctx_tx
+            .borrow()
+            .state
+            .channel
+            .direct_messages
+            .iter()
+            .any(|channel| channel.id == channel_id);
+
+        let mentions = match info.last_read {
+            Some(last_read) if count > 0 && !direct => {
+                datasources::slack::get_conversations_history(
+                    &client,
+                    token.clone(),
+                    channel_id.clone(),
+                )
+                .await
+                .map_or(0, |messages| {
+                    utils::mention::count(&messages, &user_id, &last_read)
+                })
+            }
+            _ => 0,
+        };
+
+        if ctx_tx.borrow().is_exit() {
+            break;
+        }
+
+        if info.unread_count_display.is_some() {
+            ctx_tx.send_modify(|context| {
+                let channel_state = &mut context.state.channel;
+                let opened = channel_state.opened.as_ref().map(|opened| &opened.id);
+                if opened != Some(&channel_id) {
+                    channel_state.set_unread(&channel_id, count);
+                    channel_state.set_mentions(&channel_id, mentions);
+                }
+            });
+        }
+    }
+}
+
 async fn ui_thread(config: Configuration, ctx_rx: watch::Receiver<Context>) {
     let mut stdout = std::io::stdout();
-    execute!(stdout, EnterAlternateScreen).unwrap();
+    execute!(stdout, EnterAlternateScreen, EnableBracketedPaste).unwrap();
+    let _ = execute!(
+        stdout,
+        PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES)
+    );
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend).unwrap();
 
@@ -198,9 +730,24 @@ async fn ui_thread(config: Configuration, ctx_rx: watch::Receiver<Context>) {
 
     let mut cache: Cache<'static> = Cache::new();
 
+    let mut suspended = false;
+    let mut old_clock = String::new();
+
     loop {
         let context = ctx_rx.borrow().clone();
 
+        if context.suspended {
+            suspended = true;
+            time::sleep(Duration::from_millis(10)).await;
+            continue;
+        }
+
+        if suspended {
+            suspended = false;
+            old_context = None;
+            terminal.clear().unwrap();
+        }
+
         if context.loading {
             widgets::loading::build(
                 &terminal.size().unwrap(),
@@ -209,7 +756,17 @@ async fn ui_thread(config: Configuration, ctx_rx: watch::Receiver<Context>) {
             );
         }
 
-        if old_context.clone().is_none_or(|value| value != context) {
+        let clock = Local::now().format("%H:%M").to_string();
+
+        if old_context.clone().is_none_or(|value| value != context) || old_clock != clock {
+            // Rendered lines embed names and emoji resolved from the workspace
+            if old_context
+                .as_ref()
+                .is_some_and(|old_context| !same_workspace(old_context, &context))
+            {
+                cache.clear();
+            }
+
             if old_context.map_or(false, |context| context.loading) && !context.loading {
                 execute!(std::io::stdout(), Clear(ClearType::All)).unwrap();
                 terminal.clear().unwrap();
@@ -220,18 +777,27 @@ async fn ui_thread(config: Configuration, ctx_rx: watch::Receiver<Context>) {
             }
 
             let build = route::get(context.current_route()).build;
+            let command_line = widgets::command_line::get().build;
 
             terminal
                 .draw(|frame| {
                     build(&config, frame, &context, &mut cache);
+                    command_line(&config, frame, &context, &mut cache, frame.area());
                 })
                 .unwrap();
 
             old_context = Some(context.clone());
+            old_clock = clock;
         }
 
         time::sleep(Duration::from_millis(10)).await;
     }
 
-    execute!(terminal.backend_mut(), LeaveAlternateScreen).unwrap();
+    let _ = execute!(terminal.backend_mut(), PopKeyboardEnhancementFlags);
+    execute!(
+        terminal.backend_mut(),
+        DisableBracketedPaste,
+        LeaveAlternateScreen
+    )
+    .unwrap();
 }
@@ -8,6 +8,7 @@ use crate::enums::widgets::Widgets;
 pub struct Cache<'cache> {
     pub widget: HashMap<String, Widgets<'cache>>,
     pub widgets: HashMap<String, Vec<Widgets<'cache>>>,
+    pub messages: HashMap<String, (String, Vec<Line<'cache>>)>,
 }
 
 impl Cache<'_> {
@@ -15,6 +16,13 @@ impl Cache<'_> {
         Cache {
             widget: HashMap::new(),
             widgets: HashMap::new(),
+            messages: HashMap::new(),
         }
     }
+
+    pub fn clear(&mut self) {
+        self.widget.clear();
+        self.widgets.clear();
+        self.messages.clear();
+    }
 }
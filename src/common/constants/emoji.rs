@@ -0,0 +1,667 @@
+pub static EMOJI: &[(&str, &str)] = &[
+    ("+1", "\u{1f44d}"),
+    ("-1", "\u{1f44e}"),
+    ("100", "\u{1f4af}"),
+    ("1234", "\u{1f522}"),
+    ("8ball", "\u{1f3b1}"),
+    ("abc", "\u{1f524}"),
+    ("adult", "\u{1f9d1}"),
+    ("airplane", "\u{2708}\u{fe0f}"),
+    ("alarm_clock", "\u{23f0}"),
+    ("alien", "\u{1f47d}"),
+    ("ambulance", "\u{1f691}"),
+    ("anchor", "\u{2693}"),
+    ("anger", "\u{1f4a2}"),
+    ("angry", "\u{1f620}"),
+    ("anguished", "\u{1f627}"),
+    ("apple", "\u{1f34e}"),
+    ("arrow_down", "\u{2b07}\u{fe0f}"),
+    ("arrow_left", "\u{2b05}\u{fe0f}"),
+    ("arrow_lower_left", "\u{2199}\u{fe0f}"),
+    ("arrow_lower_right", "\u{2198}\u{fe0f}"),
+    ("arrow_right", "\u{27a1}\u{fe0f}"),
+    ("arrow_up", "\u{2b06}\u{fe0f}"),
+    ("arrow_upper_left", "\u{2196}\u{fe0f}"),
+    ("arrow_upper_right", "\u{2197}\u{fe0f}"),
+    ("arrows_clockwise", "\u{1f503}"),
+    ("arrows_counterclockwise", "\u{1f504}"),
+    ("art", "\u{1f3a8}"),
+    ("astonished", "\u{1f632}"),
+    ("avocado", "\u{1f951}"),
+    ("baby", "\u{1f476}"),
+    ("back", "\u{1f519}"),
+    ("bacon", "\u{1f953}"),
+    ("balloon", "\u{1f388}"),
+    ("ballot_box_with_check", "\u{2611}\u{fe0f}"),
+    ("banana", "\u{1f34c}"),
+    ("bangbang", "\u{203c}\u{fe0f}"),
+    ("bank", "\u{1f3e6}"),
+    ("bar_chart", "\u{1f4ca}"),
+    ("baseball", "\u{26be}"),
+    ("basketball", "\u{1f3c0}"),
+    ("bathtub", "\u{1f6c1}"),
+    ("battery", "\u{1f50b}"),
+    ("bear", "\u{1f43b}"),
+    ("bed", "\u{1f6cf}\u{fe0f}"),
+    ("bee", "\u{1f41d}"),
+    ("beer", "\u{1f37a}"),
+    ("beers", "\u{1f37b}"),
+    ("bell", "\u{1f514}"),
+    ("bento", "\u{1f371}"),
+    ("bike", "\u{1f6b2}"),
+    ("biohazard", "\u{2623}\u{fe0f}"),
+    ("biohazard_sign", "\u{2623}\u{fe0f}"),
+    ("bird", "\u{1f426}"),
+    ("birthday", "\u{1f382}"),
+    ("black_circle", "\u{26ab}"),
+    ("black_heart", "\u{1f5a4}"),
+    ("black_large_square", "\u{2b1b}"),
+    ("blue_heart", "\u{1f499}"),
+    ("blush", "\u{1f60a}"),
+    ("boat", "\u{26f5}"),
+    ("bomb", "\u{1f4a3}"),
+    ("book", "\u{1f4d6}"),
+    ("bookmark", "\u{1f516}"),
+    ("books", "\u{1f4da}"),
+    ("boom", "\u{1f4a5}"),
+    ("bouquet", "\u{1f490}"),
+    ("bow", "\u{1f647}"),
+    ("boy", "\u{1f466}"),
+    ("brain", "\u{1f9e0}"),
+    ("bread", "\u{1f35e}"),
+    ("broken_heart", "\u{1f494}"),
+    ("broom", "\u{1f9f9}"),
+    ("bug", "\u{1f41b}"),
+    ("bulb", "\u{1f4a1}"),
+    ("burrito", "\u{1f32f}"),
+    ("bus", "\u{1f68c}"),
+    ("butterfly", "\u{1f98b}"),
+    ("cactus", "\u{1f335}"),
+    ("cake", "\u{1f370}"),
+    ("calendar", "\u{1f4c6}"),
+    ("call_me_hand", "\u{1f919}"),
+    ("calling", "\u{1f4f2}"),
+    ("camera", "\u{1f4f7}"),
+    ("camera_with_flash", "\u{1f4f8}"),
+    ("camping", "\u{1f3d5}\u{fe0f}"),
+    ("candle", "\u{1f56f}\u{fe0f}"),
+    ("candy", "\u{1f36c}"),
+    ("car", "\u{1f697}"),
+    ("card_index_dividers", "\u{1f5c2}\u{fe0f}"),
+    ("carrot", "\u{1f955}"),
+    ("cat", "\u{1f431}"),
+    ("cd", "\u{1f4bf}"),
+    ("champagne", "\u{1f37e}"),
+    ("chart_with_downwards_trend", "\u{1f4c9}"),
+    ("chart_with_upwards_trend", "\u{1f4c8}"),
+    ("checkered_flag", "\u{1f3c1}"),
+    ("cheese", "\u{1f9c0}"),
+    ("cheese_wedge", "\u{1f9c0}"),
+    ("cherries", "\u{1f352}"),
+    ("cherry_blossom", "\u{1f338}"),
+    ("chess_pawn", "\u{265f}\u{fe0f}"),
+    ("chicken", "\u{1f414}"),
+    ("child", "\u{1f9d2}"),
+    ("children_crossing", "\u{1f6b8}"),
+    ("chocolate_bar", "\u{1f36b}"),
+    ("christmas_tree", "\u{1f384}"),
+    ("clap", "\u{1f44f}"),
+    ("clinking_glasses", "\u{1f942}"),
+    ("clipboard", "\u{1f4cb}"),
+    ("cloud", "\u{2601}\u{fe0f}"),
+    ("clown_face", "\u{1f921}"),
+    ("cocktail", "\u{1f378}"),
+    ("coconut", "\u{1f965}"),
+    ("coffee", "\u{2615}"),
+    ("coffin", "\u{26b0}\u{fe0f}"),
+    ("cold_face", "\u{1f976}"),
+    ("cold_sweat", "\u{1f630}"),
+    ("collision", "\u{1f4a5}"),
+    ("computer", "\u{1f4bb}"),
+    ("computer_mouse", "\u{1f5b1}\u{fe0f}"),
+    ("confetti_ball", "\u{1f38a}"),
+    ("confounded", "\u{1f616}"),
+    ("confused", "\u{1f615}"),
+    ("construction", "\u{1f6a7}"),
+    ("cookie", "\u{1f36a}"),
+    ("cooking", "\u{1f373}"),
+    ("cool", "\u{1f192}"),
+    ("copyright", "\u{a9}\u{fe0f}"),
+    ("corn", "\u{1f33d}"),
+    ("couch_and_lamp", "\u{1f6cb}\u{fe0f}"),
+    ("cow", "\u{1f42e}"),
+    ("cowboy_hat_face", "\u{1f920}"),
+    ("crab", "\u{1f980}"),
+    ("credit_card", "\u{1f4b3}"),
+    ("crescent_moon", "\u{1f319}"),
+    ("croissant", "\u{1f950}"),
+    ("crossed_fingers", "\u{1f91e}"),
+    ("cry", "\u{1f622}"),
+    ("crying_cat_face", "\u{1f63f}"),
+    ("crystal_ball", "\u{1f52e}"),
+    ("cup_with_straw", "\u{1f964}"),
+    ("cupcake", "\u{1f9c1}"),
+    ("cupid", "\u{1f498}"),
+    ("curry", "\u{1f35b}"),
+    ("dancer", "\u{1f483}"),
+    ("dart", "\u{1f3af}"),
+    ("dash", "\u{1f4a8}"),
+    ("date", "\u{1f4c5}"),
+    ("deciduous_tree", "\u{1f333}"),
+    ("desert_island", "\u{1f3dd}\u{fe0f}"),
+    ("desktop_computer", "\u{1f5a5}\u{fe0f}"),
+    ("detective", "\u{1f575}\u{fe0f}"),
+    ("disappointed", "\u{1f61e}"),
+    ("disappointed_relieved", "\u{1f625}"),
+    ("dizzy", "\u{1f4ab}"),
+    ("dizzy_face", "\u{1f635}"),
+    ("dna", "\u{1f9ec}"),
+    ("dog", "\u{1f436}"),
+    ("dollar", "\u{1f4b5}"),
+    ("dolphin", "\u{1f42c}"),
+    ("door", "\u{1f6aa}"),
+    ("doughnut", "\u{1f369}"),
+    ("dragon", "\u{1f409}"),
+    ("drooling_face", "\u{1f924}"),
+    ("droplet", "\u{1f4a7}"),
+    ("drum_with_drumsticks", "\u{1f941}"),
+    ("duck", "\u{1f986}"),
+    ("dumpling", "\u{1f95f}"),
+    ("dvd", "\u{1f4c0}"),
+    ("eagle", "\u{1f985}"),
+    ("earth_africa", "\u{1f30d}"),
+    ("earth_americas", "\u{1f30e}"),
+    ("earth_asia", "\u{1f30f}"),
+    ("egg", "\u{1f95a}"),
+    ("eggplant", "\u{1f346}"),
+    ("eight", "\u{38}\u{fe0f}\u{20e3}"),
+    ("electric_plug", "\u{1f50c}"),
+    ("email", "\u{2709}\u{fe0f}"),
+    ("end", "\u{1f51a}"),
+    ("envelope", "\u{2709}\u{fe0f}"),
+    ("european_castle", "\u{1f3f0}"),
+    ("evergreen_tree", "\u{1f332}"),
+    ("exclamation", "\u{2757}"),
+    ("exploding_head", "\u{1f92f}"),
+    ("expressionless", "\u{1f611}"),
+    ("eye", "\u{1f441}\u{fe0f}"),
+    ("eyes", "\u{1f440}"),
+    ("eyes_rolling", "\u{1f644}"),
+    ("face_holding_back_tears", "\u{1f979}"),
+    ("face_palm", "\u{1f926}"),
+    ("face_vomiting", "\u{1f92e}"),
+    ("face_with_cowboy_hat", "\u{1f920}"),
+    ("face_with_hand_over_mouth", "\u{1f92d}"),
+    ("face_with_head_bandage", "\u{1f915}"),
+    ("face_with_monocle", "\u{1f9d0}"),
+    ("face_with_raised_eyebrow", "\u{1f928}"),
+    ("face_with_rolling_eyes", "\u{1f644}"),
+    ("face_with_symbols_on_mouth", "\u{1f92c}"),
+    ("face_with_thermometer", "\u{1f912}"),
+    ("facepalm", "\u{1f926}"),
+    ("facepunch", "\u{1f44a}"),
+    ("factory", "\u{1f3ed}"),
+    ("fallen_leaf", "\u{1f342}"),
+    ("fax", "\u{1f4e0}"),
+    ("fearful", "\u{1f628}"),
+    ("female_sign", "\u{2640}\u{fe0f}"),
+    ("file_folder", "\u{1f4c1}"),
+    ("fire", "\u{1f525}"),
+    ("fire_engine", "\u{1f692}"),
+    ("fireworks", "\u{1f386}"),
+    ("first_place_medal", "\u{1f947}"),
+    ("fish", "\u{1f41f}"),
+    ("fist", "\u{270a}"),
+    ("five", "\u{35}\u{fe0f}\u{20e3}"),
+    ("flashlight", "\u{1f526}"),
+    ("flipper", "\u{1f42c}"),
+    ("floppy_disk", "\u{1f4be}"),
+    ("flushed", "\u{1f633}"),
+    ("football", "\u{1f3c8}"),
+    ("four", "\u{34}\u{fe0f}\u{20e3}"),
+    ("four_leaf_clover", "\u{1f340}"),
+    ("fox_face", "\u{1f98a}"),
+    ("free", "\u{1f193}"),
+    ("fried_egg", "\u{1f373}"),
+    ("fries", "\u{1f35f}"),
+    ("frog", "\u{1f438}"),
+    ("frowning", "\u{1f626}"),
+    ("frowning_face", "\u{2639}\u{fe0f}"),
+    ("fuelpump", "\u{26fd}"),
+    ("full_moon", "\u{1f315}"),
+    ("game_die", "\u{1f3b2}"),
+    ("gear", "\u{2699}\u{fe0f}"),
+    ("gem", "\u{1f48e}"),
+    ("ghost", "\u{1f47b}"),
+    ("gift", "\u{1f381}"),
+    ("gift_heart", "\u{1f49d}"),
+    ("girl", "\u{1f467}"),
+    ("golf", "\u{26f3}"),
+    ("grapes", "\u{1f347}"),
+    ("green_apple", "\u{1f34f}"),
+    ("green_heart", "\u{1f49a}"),
+    ("grey_exclamation", "\u{2755}"),
+    ("grey_question", "\u{2754}"),
+    ("grimacing", "\u{1f62c}"),
+    ("grin", "\u{1f601}"),
+    ("grinning", "\u{1f600}"),
+    ("guitar", "\u{1f3b8}"),
+    ("hamburger", "\u{1f354}"),
+    ("hammer", "\u{1f528}"),
+    ("hammer_and_wrench", "\u{1f6e0}\u{fe0f}"),
+    ("hamster", "\u{1f439}"),
+    ("hand", "\u{270b}"),
+    ("hand_with_index_and_middle_fingers_crossed", "\u{1f91e}"),
+    ("handshake", "\u{1f91d}"),
+    ("hankey", "\u{1f4a9}"),
+    ("hash", "\u{23}\u{fe0f}\u{20e3}"),
+    ("headphones", "\u{1f3a7}"),
+    ("hear_no_evil", "\u{1f649}"),
+    ("heart", "\u{2764}\u{fe0f}"),
+    ("heart_eyes", "\u{1f60d}"),
+    ("heart_eyes_cat", "\u{1f63b}"),
+    ("heart_hands", "\u{1faf6}"),
+    ("heartbeat", "\u{1f493}"),
+    ("heartpulse", "\u{1f497}"),
+    ("heavy_check_mark", "\u{2714}\u{fe0f}"),
+    ("heavy_division_sign", "\u{2797}"),
+    ("heavy_exclamation_mark", "\u{2757}"),
+    ("heavy_heart_exclamation_mark_ornament", "\u{2763}\u{fe0f}"),
+    ("heavy_minus_sign", "\u{2796}"),
+    ("heavy_multiplication_x", "\u{2716}\u{fe0f}"),
+    ("heavy_plus_sign", "\u{2795}"),
+    ("helicopter", "\u{1f681}"),
+    ("herb", "\u{1f33f}"),
+    ("honeybee", "\u{1f41d}"),
+    ("hospital", "\u{1f3e5}"),
+    ("hot_face", "\u{1f975}"),
+    ("hot_pepper", "\u{1f336}\u{fe0f}"),
+    ("hotdog", "\u{1f32d}"),
+    ("hotel", "\u{1f3e8}"),
+    ("hourglass", "\u{231b}"),
+    ("hourglass_flowing_sand", "\u{23f3}"),
+    ("house", "\u{1f3e0}"),
+    ("house_with_garden", "\u{1f3e1}"),
+    ("hugging_face", "\u{1f917}"),
+    ("hugs", "\u{1f917}"),
+    ("hushed", "\u{1f62f}"),
+    ("i_love_you_hand_sign", "\u{1f91f}"),
+    ("icecream", "\u{1f366}"),
+    ("imp", "\u{1f47f}"),
+    ("inbox_tray", "\u{1f4e5}"),
+    ("incoming_envelope", "\u{1f4e8}"),
+    ("infinity", "\u{267e}\u{fe0f}"),
+    ("information_desk_person", "\u{1f481}"),
+    ("information_source", "\u{2139}\u{fe0f}"),
+    ("innocent", "\u{1f607}"),
+    ("interrobang", "\u{2049}\u{fe0f}"),
+    ("iphone", "\u{1f4f1}"),
+    ("jack_o_lantern", "\u{1f383}"),
+    ("japanese_ogre", "\u{1f479}"),
+    ("jigsaw", "\u{1f9e9}"),
+    ("joy", "\u{1f602}"),
+    ("joy_cat", "\u{1f639}"),
+    ("key", "\u{1f511}"),
+    ("keyboard", "\u{2328}\u{fe0f}"),
+    ("keycap_ten", "\u{1f51f}"),
+    ("kiss", "\u{1f48b}"),
+    ("kissing", "\u{1f617}"),
+    ("kissing_closed_eyes", "\u{1f61a}"),
+    ("kissing_heart", "\u{1f618}"),
+    ("kissing_smiling_eyes", "\u{1f619}"),
+    ("kiwifruit", "\u{1f95d}"),
+    ("knife_fork_plate", "\u{1f37d}\u{fe0f}"),
+    ("koala", "\u{1f428}"),
+    ("label", "\u{1f3f7}\u{fe0f}"),
+    ("large_blue_circle", "\u{1f535}"),
+    ("large_blue_diamond", "\u{1f537}"),
+    ("large_blue_square", "\u{1f7e6}"),
+    ("large_green_circle", "\u{1f7e2}"),
+    ("large_green_square", "\u{1f7e9}"),
+    ("large_orange_circle", "\u{1f7e0}"),
+    ("large_orange_diamond", "\u{1f536}"),
+    ("large_purple_circle", "\u{1f7e3}"),
+    ("large_red_square", "\u{1f7e5}"),
+    ("large_yellow_circle", "\u{1f7e1}"),
+    ("laughing", "\u{1f606}"),
+    ("left-facing_fist", "\u{1f91b}"),
+    ("lemon", "\u{1f34b}"),
+    ("link", "\u{1f517}"),
+    ("lion", "\u{1f981}"),
+    ("lion_face", "\u{1f981}"),
+    ("lips", "\u{1f444}"),
+    ("lock", "\u{1f512}"),
+    ("lollipop", "\u{1f36d}"),
+    ("loud_sound", "\u{1f50a}"),
+    ("loudspeaker", "\u{1f4e2}"),
+    ("love_letter", "\u{1f48c}"),
+    ("lower_left_ballpoint_pen", "\u{1f58a}\u{fe0f}"),
+    ("lying_face", "\u{1f925}"),
+    ("mag", "\u{1f50d}"),
+    ("mag_right", "\u{1f50e}"),
+    ("mailbox", "\u{1f4eb}"),
+    ("male_sign", "\u{2642}\u{fe0f}"),
+    ("man", "\u{1f468}"),
+    ("man-facepalming", "\u{1f926}\u{200d}\u{2642}\u{fe0f}"),
+    ("man-shrugging", "\u{1f937}\u{200d}\u{2642}\u{fe0f}"),
+    ("man-technologist", "\u{1f468}\u{200d}\u{1f4bb}"),
+    ("man_dancing", "\u{1f57a}"),
+    ("mango", "\u{1f96d}"),
+    ("maple_leaf", "\u{1f341}"),
+    ("mask", "\u{1f637}"),
+    ("medal", "\u{1f396}\u{fe0f}"),
+    ("mega", "\u{1f4e3}"),
+    ("melting_face", "\u{1fae0}"),
+    ("memo", "\u{1f4dd}"),
+    ("metal", "\u{1f918}"),
+    ("microphone", "\u{1f3a4}"),
+    ("microscope", "\u{1f52c}"),
+    ("middle_finger", "\u{1f595}"),
+    ("money_mouth_face", "\u{1f911}"),
+    ("moneybag", "\u{1f4b0}"),
+    ("monkey_face", "\u{1f435}"),
+    ("motorcycle", "\u{1f3cd}\u{fe0f}"),
+    ("mount_fuji", "\u{1f5fb}"),
+    ("mouse", "\u{1f42d}"),
+    ("movie_camera", "\u{1f3a5}"),
+    ("moyai", "\u{1f5ff}"),
+    ("muscle", "\u{1f4aa}"),
+    ("mushroom", "\u{1f344}"),
+    ("musical_note", "\u{1f3b5}"),
+    ("mute", "\u{1f507}"),
+    ("nail_care", "\u{1f485}"),
+    ("nauseated_face", "\u{1f922}"),
+    ("negative_squared_cross_mark", "\u{274e}"),
+    ("nerd_face", "\u{1f913}"),
+    ("neutral_face", "\u{1f610}"),
+    ("new", "\u{1f195}"),
+    ("new_moon", "\u{1f311}"),
+    ("newspaper", "\u{1f4f0}"),
+    ("ng", "\u{1f196}"),
+    ("nine", "\u{39}\u{fe0f}\u{20e3}"),
+    ("ninja", "\u{1f977}"),
+    ("no_bell", "\u{1f515}"),
+    ("no_entry", "\u{26d4}"),
+    ("no_entry_sign", "\u{1f6ab}"),
+    ("no_good", "\u{1f645}"),
+    ("no_mouth", "\u{1f636}"),
+    ("notebook", "\u{1f4d3}"),
+    ("notes", "\u{1f3b6}"),
+    ("nut_and_bolt", "\u{1f529}"),
+    ("ocean", "\u{1f30a}"),
+    ("octopus", "\u{1f419}"),
+    ("office", "\u{1f3e2}"),
+    ("ok", "\u{1f197}"),
+    ("ok_hand", "\u{1f44c}"),
+    ("ok_woman", "\u{1f646}"),
+    ("old_key", "\u{1f5dd}\u{fe0f}"),
+    ("older_adult", "\u{1f9d3}"),
+    ("on", "\u{1f51b}"),
+    ("one", "\u{31}\u{fe0f}\u{20e3}"),
+    ("open_file_folder", "\u{1f4c2}"),
+    ("open_hands", "\u{1f450}"),
+    ("open_mouth", "\u{1f62e}"),
+    ("orange_heart", "\u{1f9e1}"),
+    ("outbox_tray", "\u{1f4e4}"),
+    ("owl", "\u{1f989}"),
+    ("package", "\u{1f4e6}"),
+    ("pager", "\u{1f4df}"),
+    ("palm_tree", "\u{1f334}"),
+    ("palms_up_together", "\u{1f932}"),
+    ("pancakes", "\u{1f95e}"),
+    ("panda_face", "\u{1f43c}"),
+    ("paperclip", "\u{1f4ce}"),
+    ("partly_sunny", "\u{26c5}"),
+    ("partying_face", "\u{1f973}"),
+    ("peach", "\u{1f351}"),
+    ("pear", "\u{1f350}"),
+    ("pen", "\u{1f58a}\u{fe0f}"),
+    ("pencil", "\u{1f4dd}"),
+    ("pencil2", "\u{270f}\u{fe0f}"),
+    ("penguin", "\u{1f427}"),
+    ("pensive", "\u{1f614}"),
+    ("performing_arts", "\u{1f3ad}"),
+    ("persevere", "\u{1f623}"),
+    ("person_frowning", "\u{1f64d}"),
+    ("person_with_pouting_face", "\u{1f64e}"),
+    ("phone", "\u{260e}\u{fe0f}"),
+    ("pig", "\u{1f437}"),
+    ("pill", "\u{1f48a}"),
+    ("pinching_hand", "\u{1f90f}"),
+    ("pineapple", "\u{1f34d}"),
+    ("pirate_flag", "\u{1f3f4}\u{200d}\u{2620}\u{fe0f}"),
+    ("pizza", "\u{1f355}"),
+    ("pleading_face", "\u{1f97a}"),
+    ("point_down", "\u{1f447}"),
+    ("point_left", "\u{1f448}"),
+    ("point_right", "\u{1f449}"),
+    ("point_up", "\u{261d}\u{fe0f}"),
+    ("point_up_2", "\u{1f446}"),
+    ("police_car", "\u{1f693}"),
+    ("poop", "\u{1f4a9}"),
+    ("popcorn", "\u{1f37f}"),
+    ("potato", "\u{1f954}"),
+    ("pout", "\u{1f621}"),
+    ("pray", "\u{1f64f}"),
+    ("printer", "\u{1f5a8}\u{fe0f}"),
+    ("punch", "\u{1f44a}"),
+    ("purple_heart", "\u{1f49c}"),
+    ("pushpin", "\u{1f4cc}"),
+    ("question", "\u{2753}"),
+    ("rabbit", "\u{1f430}"),
+    ("radio", "\u{1f4fb}"),
+    ("radioactive", "\u{2622}\u{fe0f}"),
+    ("radioactive_sign", "\u{2622}\u{fe0f}"),
+    ("rage", "\u{1f621}"),
+    ("rainbow", "\u{1f308}"),
+    ("rainbow-flag", "\u{1f3f3}\u{fe0f}\u{200d}\u{1f308}"),
+    ("raised_back_of_hand", "\u{1f91a}"),
+    ("raised_fist", "\u{270a}"),
+    ("raised_hand", "\u{270b}"),
+    ("raised_hand_with_fingers_splayed", "\u{1f590}\u{fe0f}"),
+    ("raised_hands", "\u{1f64c}"),
+    ("raising_hand", "\u{1f64b}"),
+    ("ramen", "\u{1f35c}"),
+    ("recycle", "\u{267b}\u{fe0f}"),
+    ("red_car", "\u{1f697}"),
+    ("red_circle", "\u{1f534}"),
+    ("registered", "\u{ae}\u{fe0f}"),
+    ("relaxed", "\u{263a}\u{fe0f}"),
+    ("relieved", "\u{1f60c}"),
+    ("repeat", "\u{1f501}"),
+    ("reversed_hand_with_middle_finger_extended", "\u{1f595}"),
+    ("revolving_hearts", "\u{1f49e}"),
+    ("ribbon", "\u{1f380}"),
+    ("rice", "\u{1f35a}"),
+    ("right-facing_fist", "\u{1f91c}"),
+    ("robot_face", "\u{1f916}"),
+    ("rocket", "\u{1f680}"),
+    ("rofl", "\u{1f923}"),
+    ("roll_eyes", "\u{1f644}"),
+    ("rolling_on_the_floor_laughing", "\u{1f923}"),
+    ("rose", "\u{1f339}"),
+    ("rotating_light", "\u{1f6a8}"),
+    ("round_pushpin", "\u{1f4cd}"),
+    ("runner", "\u{1f3c3}"),
+    ("running", "\u{1f3c3}"),
+    ("sailboat", "\u{26f5}"),
+    ("saluting_face", "\u{1fae1}"),
+    ("sandwich", "\u{1f96a}"),
+    ("satellite_antenna", "\u{1f4e1}"),
+    ("satisfied", "\u{1f606}"),
+    ("school", "\u{1f3eb}"),
+    ("scissors", "\u{2702}\u{fe0f}"),
+    ("scream", "\u{1f631}"),
+    ("scream_cat", "\u{1f640}"),
+    ("second_place_medal", "\u{1f948}"),
+    ("see_no_evil", "\u{1f648}"),
+    ("seedling", "\u{1f331}"),
+    ("selfie", "\u{1f933}"),
+    ("seven", "\u{37}\u{fe0f}\u{20e3}"),
+    ("shark", "\u{1f988}"),
+    ("shield", "\u{1f6e1}\u{fe0f}"),
+    ("ship", "\u{1f6a2}"),
+    ("shit", "\u{1f4a9}"),
+    ("shopping_trolley", "\u{1f6d2}"),
+    ("shower", "\u{1f6bf}"),
+    ("shrug", "\u{1f937}"),
+    ("shushing_face", "\u{1f92b}"),
+    ("sign_of_the_horns", "\u{1f918}"),
+    ("six", "\u{36}\u{fe0f}\u{20e3}"),
+    ("skull", "\u{1f480}"),
+    ("skull_and_crossbones", "\u{2620}\u{fe0f}"),
+    ("sleeping", "\u{1f634}"),
+    ("sleepy", "\u{1f62a}"),
+    ("sleuth_or_spy", "\u{1f575}\u{fe0f}"),
+    ("slightly_frowning_face", "\u{1f641}"),
+    ("slightly_smiling_face", "\u{1f642}"),
+    ("sloth", "\u{1f9a5}"),
+    ("small_red_triangle", "\u{1f53a}"),
+    ("small_red_triangle_down", "\u{1f53b}"),
+    ("smile", "\u{1f604}"),
+    ("smile_cat", "\u{1f638}"),
+    ("smiley", "\u{1f603}"),
+    ("smiley_cat", "\u{1f63a}"),
+    ("smiling_face_with_3_hearts", "\u{1f970}"),
+    ("smiling_imp", "\u{1f608}"),
+    ("smirk", "\u{1f60f}"),
+    ("smoking", "\u{1f6ac}"),
+    ("snail", "\u{1f40c}"),
+    ("snake", "\u{1f40d}"),
+    ("sneezing_face", "\u{1f927}"),
+    ("snowflake", "\u{2744}\u{fe0f}"),
+    ("snowman", "\u{26c4}"),
+    ("sob", "\u{1f62d}"),
+    ("soccer", "\u{26bd}"),
+    ("soon", "\u{1f51c}"),
+    ("sos", "\u{1f198}"),
+    ("sound", "\u{1f509}"),
+    ("space_invader", "\u{1f47e}"),
+    ("spaghetti", "\u{1f35d}"),
+    ("sparkles", "\u{2728}"),
+    ("sparkling_heart", "\u{1f496}"),
+    ("speak_no_evil", "\u{1f64a}"),
+    ("speech_balloon", "\u{1f4ac}"),
+    ("spiral_calendar_pad", "\u{1f5d3}\u{fe0f}"),
+    ("spock-hand", "\u{1f596}"),
+    ("sports_medal", "\u{1f3c5}"),
+    ("star", "\u{2b50}"),
+    ("star-struck", "\u{1f929}"),
+    ("star2", "\u{1f31f}"),
+    ("star_struck", "\u{1f929}"),
+    ("statue_of_liberty", "\u{1f5fd}"),
+    ("steam_locomotive", "\u{1f682}"),
+    ("stop_sign", "\u{1f6d1}"),
+    ("stopwatch", "\u{23f1}\u{fe0f}"),
+    ("straight_ruler", "\u{1f4cf}"),
+    ("strawberry", "\u{1f353}"),
+    ("stuck_out_tongue", "\u{1f61b}"),
+    ("stuck_out_tongue_closed_eyes", "\u{1f61d}"),
+    ("stuck_out_tongue_winking_eye", "\u{1f61c}"),
+    ("sunflower", "\u{1f33b}"),
+    ("sunglasses", "\u{1f60e}"),
+    ("sunny", "\u{2600}\u{fe0f}"),
+    ("sushi", "\u{1f363}"),
+    ("sweat", "\u{1f613}"),
+    ("sweat_drops", "\u{1f4a6}"),
+    ("sweat_smile", "\u{1f605}"),
+    ("syringe", "\u{1f489}"),
+    ("t-rex", "\u{1f996}"),
+    ("taco", "\u{1f32e}"),
+    ("tada", "\u{1f389}"),
+    ("tangerine", "\u{1f34a}"),
+    ("taxi", "\u{1f695}"),
+    ("tea", "\u{1f375}"),
+    ("telephone", "\u{260e}\u{fe0f}"),
+    ("telephone_receiver", "\u{1f4de}"),
+    ("telescope", "\u{1f52d}"),
+    ("tennis", "\u{1f3be}"),
+    ("test_tube", "\u{1f9ea}"),
+    ("the_horns", "\u{1f918}"),
+    ("thinking", "\u{1f914}"),
+    ("thinking_face", "\u{1f914}"),
+    ("third_place_medal", "\u{1f949}"),
+    ("thought_balloon", "\u{1f4ad}"),
+    ("three", "\u{33}\u{fe0f}\u{20e3}"),
+    ("three_button_mouse", "\u{1f5b1}\u{fe0f}"),
+    ("thumbsdown", "\u{1f44e}"),
+    ("thumbsup", "\u{1f44d}"),
+    ("tiger", "\u{1f42f}"),
+    ("timer_clock", "\u{23f2}\u{fe0f}"),
+    ("tired_face", "\u{1f62b}"),
+    ("tm", "\u{2122}\u{fe0f}"),
+    ("toilet", "\u{1f6bd}"),
+    ("tomato", "\u{1f345}"),
+    ("tongue", "\u{1f445}"),
+    ("top", "\u{1f51d}"),
+    ("tornado", "\u{1f32a}\u{fe0f}"),
+    ("traffic_light", "\u{1f6a5}"),
+    ("train", "\u{1f68b}"),
+    ("triangular_flag_on_post", "\u{1f6a9}"),
+    ("triumph", "\u{1f624}"),
+    ("trophy", "\u{1f3c6}"),
+    ("tropical_drink", "\u{1f379}"),
+    ("tropical_fish", "\u{1f420}"),
+    ("truck", "\u{1f69a}"),
+    ("tulip", "\u{1f337}"),
+    ("turtle", "\u{1f422}"),
+    ("tv", "\u{1f4fa}"),
+    ("two", "\u{32}\u{fe0f}\u{20e3}"),
+    ("two_hearts", "\u{1f495}"),
+    ("umbrella", "\u{2614}"),
+    ("unamused", "\u{1f612}"),
+    ("unicorn_face", "\u{1f984}"),
+    ("unlock", "\u{1f513}"),
+    ("up", "\u{1f199}"),
+    ("upside_down_face", "\u{1f643}"),
+    ("v", "\u{270c}\u{fe0f}"),
+    ("vertical_traffic_light", "\u{1f6a6}"),
+    ("video_camera", "\u{1f4f9}"),
+    ("video_game", "\u{1f3ae}"),
+    ("volcano", "\u{1f30b}"),
+    ("volleyball", "\u{1f3d0}"),
+    ("vulcan_salute", "\u{1f596}"),
+    ("walking", "\u{1f6b6}"),
+    ("warning", "\u{26a0}\u{fe0f}"),
+    ("wastebasket", "\u{1f5d1}\u{fe0f}"),
+    ("watch", "\u{231a}"),
+    ("watermelon", "\u{1f349}"),
+    ("wave", "\u{1f44b}"),
+    ("waving_black_flag", "\u{1f3f4}"),
+    ("waving_white_flag", "\u{1f3f3}\u{fe0f}"),
+    ("weary", "\u{1f629}"),
+    ("whale", "\u{1f433}"),
+    ("white_check_mark", "\u{2705}"),
+    ("white_circle", "\u{26aa}"),
+    ("white_frowning_face", "\u{2639}\u{fe0f}"),
+    ("white_heart", "\u{1f90d}"),
+    ("white_large_square", "\u{2b1c}"),
+    ("wine_glass", "\u{1f377}"),
+    ("wink", "\u{1f609}"),
+    ("wolf", "\u{1f43a}"),
+    ("woman", "\u{1f469}"),
+    ("woman-facepalming", "\u{1f926}\u{200d}\u{2640}\u{fe0f}"),
+    ("woman-shrugging", "\u{1f937}\u{200d}\u{2640}\u{fe0f}"),
+    ("woman-technologist", "\u{1f469}\u{200d}\u{1f4bb}"),
+    ("woozy_face", "\u{1f974}"),
+    ("worried", "\u{1f61f}"),
+    ("wrench", "\u{1f527}"),
+    ("writing_hand", "\u{270d}\u{fe0f}"),
+    ("x", "\u{274c}"),
+    ("yawning_face", "\u{1f971}"),
+    ("yellow_heart", "\u{1f49b}"),
+    ("yum", "\u{1f60b}"),
+    ("zany_face", "\u{1f92a}"),
+    ("zap", "\u{26a1}"),
+    ("zero", "\u{30}\u{fe0f}\u{20e3}"),
+    ("zipper_mouth_face", "\u{1f910}"),
+    ("zzz", "\u{1f4a4}"),
+];
+
+pub static SKIN_TONES: [&str; 5] = [
+    "\u{1f3fb}",
+    "\u{1f3fc}",
+    "\u{1f3fd}",
+    "\u{1f3fe}",
+    "\u{1f3ff}",
+];
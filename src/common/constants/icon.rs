@@ -2,3 +2,4 @@ pub static USER: &str = "\u{eabc}";
 pub static GROUP: &str = "\u{f0c0}";
 pub static LOCK: &str = "\u{e672}";
 pub static HASHTAG: &str = "\u{f4df}";
+pub static DRAFT: &str = "\u{f040}";
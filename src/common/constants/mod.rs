@@ -1 +1,2 @@
+pub mod emoji;
 pub mod icon;
@@ -1,5 +1,11 @@
 pub enum Request {
     Authorization(String),
+    Reauthorization(String),
     GetConversationHistory(String),
     GetConversationReplies,
+    OpenDirectMessage(String),
+    SendMessage(String, String),
+    SendReply(String, String, String, bool),
+    OpenEditor(String),
+    EditMessage(String, String, String),
 }
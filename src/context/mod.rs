@@ -8,6 +8,7 @@ pub struct Context {
     pub routes: Vec<String>,
     pub command: String,
     pub loading: bool,
+    pub suspended: bool,
     pub auth: Option<Authorization>,
     pub state: State,
     pub focus_id: String,
@@ -20,6 +21,7 @@ impl Default for Context {
             command: String::default(),
             routes: vec![String::from("/")],
             loading: false,
+            suspended: false,
             auth: None,
             state: State::new(),
             focus_id: String::new(),
@@ -56,6 +58,14 @@ impl Context {
         self.loading = false;
     }
 
+    pub fn suspend(&mut self) {
+        self.suspended = true;
+    }
+
+    pub fn resume(&mut self) {
+        self.suspended = false;
+    }
+
     pub fn is_focus(&self, id: &String) -> bool {
         &self.focus_id == id
     }
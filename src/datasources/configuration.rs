@@ -1,8 +1,10 @@
 use std::fs;
 use std::path::PathBuf;
 
-use crate::constants;
 use crate::entities::configuration::{Configuration, PartialConfiguration};
+use crate::{constants, utils};
+
+use super::theme::get_theme;
 
 pub fn get_configuration() -> Result<Configuration, Box<dyn std::error::Error + Send + Sync>> {
     let config_path = constants::configuration::configuration_path()?;
@@ -14,12 +16,105 @@ pub fn get_configuration() -> Result<Configuration, Box<dyn std::error::Error +
         user_configuration = toml::from_str(content.as_str())?;
     }
 
-    if user_configuration.is_empty() || user_configuration.with_default_config.unwrap_or(true) {
+    let mut configuration = if user_configuration.is_empty()
+        || user_configuration.with_default_config.unwrap_or(true)
+    {
         let content = include_str!("../../assets/clack.toml");
         let configuration = toml::from_str::<Configuration>(content)?;
 
-        Ok(configuration.merge_with(user_configuration))
+        configuration.merge_with(user_configuration)
     } else {
-        Ok(user_configuration.unwrap_all())
+        user_configuration.unwrap_all()
+    };
+
+    configuration.theme = get_theme(&configuration.theme)?;
+    configuration.datetime.offset = utils::datetime::offset(&configuration.datetime.timezone)?;
+    validate_formats(&configuration)?;
+    validate_keys(&configuration)?;
+
+    Ok(configuration)
+}
+
+fn validate_formats(
+    configuration: &Configuration,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let datetime = &configuration.datetime;
+
+    let formats = [
+        ("datetime.time_format", &datetime.time_format),
+        ("datetime.time_format_12h", &datetime.time_format_12h),
+        ("datetime.date_format", &datetime.date_format),
+        ("datetime.full_format", &datetime.full_format),
+    ];
+
+    for (name, format) in formats {
+        utils::datetime::validate(format)
+            .map_err(|error| format!("Invalid format for {}: \"{}\" ({})", name, format, error))?;
+    }
+
+    Ok(())
+}
+
+fn validate_keys(
+    configuration: &Configuration,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let keymaps = &configuration.keymaps;
+
+    let keys = [
+        ("keymaps.up", &keymaps.up),
+        ("keymaps.down", &keymaps.down),
+        ("keymaps.left", &keymaps.left),
+        ("keymaps.right", &keymaps.right),
+        ("keymaps.exit", &keymaps.exit),
+        ("keymaps.open", &keymaps.open),
+        ("keymaps.search", &keymaps.search),
+        ("keymaps.switcher", &keymaps.switcher),
+        ("keymaps.interact", &keymaps.interact),
+        ("keymaps.send", &keymaps.send),
+        ("keymaps.editor", &keymaps.editor),
+        ("keymaps.broadcast", &keymaps.broadcast),
+        ("keymaps.star", &keymaps.star),
+        ("keymaps.command", &keymaps.command),
+        ("keymaps.focus.up", &keymaps.focus.up),
+        ("keymaps.focus.down", &keymaps.focus.down),
+        ("keymaps.focus.left", &keymaps.focus.left),
+        ("keymaps.focus.right", &keymaps.focus.right),
+        ("keymaps.layout.grow", &keymaps.layout.grow),
+        ("keymaps.layout.shrink", &keymaps.layout.shrink),
+        ("keymaps.layout.sidebar", &keymaps.layout.sidebar),
+        ("keymaps.layout.zoom", &keymaps.layout.zoom),
+    ];
+
+    for (name, key) in keys {
+        utils::keycode::parse(key)
+            .map_err(|error| format!("Invalid key for {}: \"{}\" ({})", name, key, error))?;
     }
+
+    let mut sequences = vec![
+        (String::from("keymaps.top"), &keymaps.top),
+        (String::from("keymaps.bottom"), &keymaps.bottom),
+        (String::from("keymaps.links"), &keymaps.links),
+    ];
+    let bindings = &configuration.bindings;
+    sequences.extend(
+        bindings
+            .global
+            .keys()
+            .map(|key| (String::from("bindings.global"), key)),
+    );
+    for (scope, scoped) in [("routes", &bindings.routes), ("widgets", &bindings.widgets)] {
+        for (name, keys) in scoped {
+            sequences.extend(
+                keys.keys()
+                    .map(|key| (format!("bindings.{}.{}", scope, name), key)),
+            );
+        }
+    }
+
+    for (name, key) in sequences {
+        utils::keycode::parse_sequence(key)
+            .map_err(|error| format!("Invalid key for {}: \"{}\" ({})", name, key, error))?;
+    }
+
+    Ok(())
 }
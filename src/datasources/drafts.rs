@@ -0,0 +1,14 @@
+use std::collections::HashMap;
+
+use super::cache::{get_cache, store_cache};
+
+pub fn get_drafts() -> HashMap<String, String> {
+    get_cache::<HashMap<String, String>>(String::from("drafts")).unwrap_or_default()
+}
+
+pub fn store_drafts(
+    drafts: &HashMap<String, String>,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let data = serde_json::to_string(drafts)?;
+    store_cache(String::from("drafts"), data)
+}
@@ -0,0 +1,10 @@
+use super::cache::{get_cache, store_cache};
+
+pub fn get_history() -> Vec<String> {
+    get_cache::<Vec<String>>(String::from("history")).unwrap_or_default()
+}
+
+pub fn store_history(history: &[String]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let data = serde_json::to_string(history)?;
+    store_cache(String::from("history"), data)
+}
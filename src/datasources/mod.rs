@@ -1,3 +1,7 @@
 pub mod cache;
 pub mod configuration;
+pub mod drafts;
+pub mod history;
 pub mod slack;
+pub mod starred;
+pub mod theme;
@@ -1,24 +1,54 @@
 use std::{
     collections::HashMap,
+    fs,
     sync::{Arc, Mutex},
+    time::Duration,
 };
 
 use reqwest::{
     header::{HeaderMap, HeaderValue, AUTHORIZATION},
-    Client, Url,
+    Certificate, Client, NoProxy, Proxy, Url,
 };
 use tokio::sync::oneshot;
 use warp::Filter;
 
 use crate::entities::{
     self,
+    configuration::Network,
     slack::{
-        authorization::Authorization, conversations::Channel, messages::Message, users::Member,
+        authorization::Authorization,
+        conversations::{Channel, InfoResponse},
+        messages::{Message, PostResponse},
+        usergroups::Usergroup,
+        users::Member,
     },
 };
 
 use super::cache::{get_cache, store_cache};
 
+pub fn client(network: &Network) -> Result<Client, Box<dyn std::error::Error + Send + Sync>> {
+    let mut builder = Client::builder();
+
+    if !network.proxy.is_empty() {
+        let proxy = Proxy::all(network.proxy.as_str())?
+            .no_proxy(NoProxy::from_string(network.no_proxy.join(",").as_str()));
+        builder = builder.proxy(proxy);
+    }
+
+    if !network.ca_bundle.is_empty() {
+        let bundle = fs::read(&network.ca_bundle)?;
+        for certificate in Certificate::from_pem_bundle(&bundle)? {
+            builder = builder.add_root_certificate(certificate);
+        }
+    }
+
+    if network.timeout > 0 {
+        builder = builder.timeout(Duration::from_secs(network.timeout));
+    }
+
+    Ok(builder.build()?)
+}
+
 pub async fn authorize_local(
 ) -> Result<entities::slack::authorization::Authorization, Box<dyn std::error::Error + Send + Sync>>
 {
@@ -29,25 +59,14 @@ pub async fn authorize_local(
 }
 
 pub async fn authorize(
+    client: &Client,
     client_id: String,
     client_secret: String,
+    user_scope: Vec<String>,
 ) -> Result<Authorization, Box<dyn std::error::Error + Send + Sync>> {
     let base_url = "https://slack.com/oauth/v2/authorize";
     let redirect_uri = "https://localhost:7777";
     let scope: Vec<&str> = vec![];
-    let user_scope: Vec<&str> = vec![
-        "users:read",
-        "usergroups:read",
-        "channels:read",
-        "channels:history",
-        "groups:read",
-        "groups:history",
-        "mpim:read",
-        "mpim:history",
-        "im:read",
-        "im:history",
-        "chat:write",
-    ];
 
     let mut auth_url = Url::parse(base_url)?;
     let mut params: HashMap<String, String> = HashMap::new();
@@ -94,7 +113,7 @@ pub async fn authorize(
         .collect();
 
     if let Some((_, code)) = query_params.iter().find(|&&(key, _)| key == "code") {
-        let result = exchange_access(client_id, client_secret, code.to_string()).await?;
+        let result = exchange_access(client, client_id, client_secret, code.to_string()).await?;
         Ok(result)
     } else {
         panic!("Invalid authorization data, please try again...");
@@ -102,12 +121,11 @@ pub async fn authorize(
 }
 
 async fn exchange_access(
+    client: &Client,
     client_id: String,
     client_secret: String,
     code: String,
 ) -> Result<Authorization, Box<dyn std::error::Error + Send + Sync>> {
-    let client = Client::new();
-
     let mut form_data: HashMap<&str, &str> = HashMap::new();
     form_data.insert("client_id", client_id.as_str());
     form_data.insert("client_secret", client_secret.as_str());
@@ -131,6 +149,7 @@ async fn exchange_access(
 }
 
 pub async fn get_conversations(
+    client: &Client,
     token: String,
 ) -> Result<Vec<Channel>, Box<dyn std::error::Error + Send + Sync>> {
     let cache_code = String::from("users.conversations");
@@ -142,7 +161,6 @@ pub async fn get_conversations(
             let mut cursor: String = String::new();
 
             loop {
-                let client = Client::new();
                 let url = "https://slack.com/api/users.conversations";
                 let mut headers = HeaderMap::new();
                 let mut params = HashMap::new();
@@ -185,6 +203,7 @@ pub async fn get_conversations(
 }
 
 pub async fn get_conversations_history(
+    client: &Client,
     token: String,
     channel: String,
 ) -> Result<Vec<Message>, Box<dyn std::error::Error + Send + Sync>> {
@@ -196,8 +215,6 @@ pub async fn get_conversations_history(
     let oldest = result
         .last()
         .map_or("0".to_string(), |message| message.ts.clone());
-
-    let client = Client::new();
     let url = "https://slack.com/api/conversations.history";
     let mut headers = HeaderMap::new();
     let mut params = HashMap::new();
@@ -233,6 +250,7 @@ pub async fn get_conversations_history(
 }
 
 pub async fn get_conversations_replies(
+    client: &Client,
     token: String,
     channel: String,
     ts: String,
@@ -248,8 +266,6 @@ pub async fn get_conversations_replies(
     let oldest = result
         .last()
         .map_or("0".to_string(), |message| message.ts.clone());
-
-    let client = Client::new();
     let url = "https://slack.com/api/conversations.replies";
     let mut headers = HeaderMap::new();
     let mut params = HashMap::new();
@@ -290,6 +306,7 @@ pub async fn get_conversations_replies(
 }
 
 pub async fn get_users_list(
+    client: &Client,
     token: String,
 ) -> Result<Vec<Member>, Box<dyn std::error::Error + Send + Sync>> {
     let cache_code = String::from("users.list");
@@ -301,7 +318,6 @@ pub async fn get_users_list(
             let mut cursor: String = String::new();
 
             loop {
-                let client = Client::new();
                 let url = "https://slack.com/api/users.list";
                 let mut headers = HeaderMap::new();
                 let mut params = HashMap::new();
@@ -343,12 +359,169 @@ pub async fn get_users_list(
     Ok(data)
 }
 
+pub async fn get_presence(
+    client: &Client,
+    token: String,
+) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+    let url = "https://slack.com/api/users.getPresence";
+    let mut headers = HeaderMap::new();
+
+    headers.insert(
+        AUTHORIZATION,
+        HeaderValue::from_str(format!("Bearer {}", token).as_str())?,
+    );
+
+    let response = client.get(url).headers(headers).send().await?;
+
+    let body_str = response.text().await?;
+    let response: entities::slack::users::PresenceResponse =
+        serde_json::from_str(body_str.as_str())?;
+
+    response.presence.ok_or("users.getPresence failed".into())
+}
+
+pub async fn get_emoji_list(
+    client: &Client,
+    token: String,
+) -> Result<HashMap<String, String>, Box<dyn std::error::Error + Send + Sync>> {
+    let cache_code = String::from("emoji.list");
+
+    let data = match get_cache::<HashMap<String, String>>(cache_code.clone()) {
+        Ok(data) => data,
+        Err(_) => {
+            let url = "https://slack.com/api/emoji.list";
+            let mut headers = HeaderMap::new();
+
+            headers.insert(
+                AUTHORIZATION,
+                HeaderValue::from_str(format!("Bearer {}", token).as_str())?,
+            );
+
+            let response = client.get(url).headers(headers).send().await?;
+
+            let body_str = response.text().await?;
+
+            let response: entities::slack::emoji::ApiResponse =
+                serde_json::from_str(body_str.as_str())?;
+
+            let result = response.emoji.unwrap_or_default();
+
+            if response.ok {
+                let data = serde_json::to_string(&result)?;
+                store_cache(cache_code, data)?;
+            }
+            result
+        }
+    };
+
+    Ok(data)
+}
+
+pub async fn get_usergroups_list(
+    client: &Client,
+    token: String,
+) -> Result<Vec<Usergroup>, Box<dyn std::error::Error + Send + Sync>> {
+    let cache_code = String::from("usergroups.list");
+
+    let data = match get_cache::<Vec<Usergroup>>(cache_code.clone()) {
+        Ok(data) => data,
+        Err(_) => {
+            let url = "https://slack.com/api/usergroups.list";
+            let mut headers = HeaderMap::new();
+
+            headers.insert(
+                AUTHORIZATION,
+                HeaderValue::from_str(format!("Bearer {}", token).as_str())?,
+            );
+
+            let response = client.get(url).headers(headers).send().await?;
+
+            let body_str = response.text().await?;
+
+            let response: entities::slack::usergroups::ApiResponse =
+                serde_json::from_str(body_str.as_str())?;
+
+            let result = response.usergroups.unwrap_or_default();
+
+            if response.ok {
+                let data = serde_json::to_string(&result)?;
+                store_cache(cache_code, data)?;
+            }
+            result
+        }
+    };
+
+    Ok(data)
+}
+
+pub async fn conversations_info(
+    client: &Client,
+    token: String,
+    channel: String,
+) -> Result<InfoResponse, Box<dyn std::error::Error + Send + Sync>> {
+    let url = "https://slack.com/api/conversations.info";
+    let mut headers = HeaderMap::new();
+    let mut params = HashMap::new();
+
+    params.insert("channel", channel.as_str());
+
+    headers.insert(
+        AUTHORIZATION,
+        HeaderValue::from_str(format!("Bearer {}", token).as_str())?,
+    );
+
+    let response = client
+        .get(url)
+        .headers(headers)
+        .query(&params)
+        .send()
+        .await?;
+
+    let body_str = response.text().await?;
+    let response: InfoResponse = serde_json::from_str(body_str.as_str())?;
+
+    Ok(response)
+}
+
+pub async fn conversations_open(
+    client: &Client,
+    token: String,
+    user: String,
+) -> Result<Channel, Box<dyn std::error::Error + Send + Sync>> {
+    let url = "https://slack.com/api/conversations.open";
+    let mut headers = HeaderMap::new();
+    let mut form_data: HashMap<&str, &str> = HashMap::new();
+
+    form_data.insert("users", user.as_str());
+    form_data.insert("return_im", "true");
+
+    headers.insert(
+        AUTHORIZATION,
+        HeaderValue::from_str(format!("Bearer {}", token).as_str())?,
+    );
+
+    let response = client
+        .post(url)
+        .headers(headers)
+        .form(&form_data)
+        .send()
+        .await?;
+
+    let body_str = response.text().await?;
+    let response: entities::slack::conversations::OpenResponse =
+        serde_json::from_str(body_str.as_str())?;
+
+    response
+        .channel
+        .ok_or(format!("conversations.open failed for {}", user).into())
+}
+
 pub async fn chat_post_message(
+    client: &Client,
     token: String,
     channel: String,
     text: String,
-) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    let client = Client::new();
+) -> Result<PostResponse, Box<dyn std::error::Error + Send + Sync>> {
     let url = "https://slack.com/api/chat.postMessage";
     let mut headers = HeaderMap::new();
     let mut form_data: HashMap<&str, &str> = HashMap::new();
@@ -362,23 +535,27 @@ pub async fn chat_post_message(
         HeaderValue::from_str(format!("Bearer {}", token).as_str())?,
     );
 
-    client
+    let response = client
         .post(url)
         .headers(headers)
         .form(&form_data)
         .send()
         .await?;
 
-    Ok(())
+    let body_str = response.text().await?;
+    let response: PostResponse = serde_json::from_str(body_str.as_str())?;
+
+    Ok(response)
 }
 
 pub async fn chat_post_message_reply(
+    client: &Client,
     token: String,
     channel: String,
     text: String,
     ts: String,
-) -> Result<(), Box<dyn std::error::Error>> {
-    let client = Client::new();
+    reply_broadcast: bool,
+) -> Result<PostResponse, Box<dyn std::error::Error + Send + Sync>> {
     let url = "https://slack.com/api/chat.postMessage";
     let mut headers = HeaderMap::new();
     let mut form_data: HashMap<&str, &str> = HashMap::new();
@@ -387,18 +564,57 @@ pub async fn chat_post_message_reply(
     form_data.insert("text", text.as_str());
     form_data.insert("as_user", "true");
     form_data.insert("thread_ts", ts.as_str());
+    if reply_broadcast {
+        form_data.insert("reply_broadcast", "true");
+    }
+
+    headers.insert(
+        AUTHORIZATION,
+        HeaderValue::from_str(format!("Bearer {}", token).as_str())?,
+    );
+
+    let response = client
+        .post(url)
+        .headers(headers)
+        .form(&form_data)
+        .send()
+        .await?;
+
+    let body_str = response.text().await?;
+    let response: PostResponse = serde_json::from_str(body_str.as_str())?;
+
+    Ok(response)
+}
+
+pub async fn chat_update(
+    client: &Client,
+    token: String,
+    channel: String,
+    ts: String,
+    text: String,
+) -> Result<PostResponse, Box<dyn std::error::Error + Send + Sync>> {
+    let url = "https://slack.com/api/chat.update";
+    let mut headers = HeaderMap::new();
+    let mut form_data: HashMap<&str, &str> = HashMap::new();
+
+    form_data.insert("channel", channel.as_str());
+    form_data.insert("ts", ts.as_str());
+    form_data.insert("text", text.as_str());
 
     headers.insert(
         AUTHORIZATION,
         HeaderValue::from_str(format!("Bearer {}", token).as_str())?,
     );
 
-    client
+    let response = client
         .post(url)
         .headers(headers)
         .form(&form_data)
         .send()
         .await?;
 
-    Ok(())
+    let body_str = response.text().await?;
+    let response: PostResponse = serde_json::from_str(body_str.as_str())?;
+
+    Ok(response)
 }
@@ -0,0 +1,10 @@
+use super::cache::{get_cache, store_cache};
+
+pub fn get_starred() -> Vec<String> {
+    get_cache::<Vec<String>>(String::from("starred")).unwrap_or_default()
+}
+
+pub fn store_starred(starred: &[String]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let data = serde_json::to_string(starred)?;
+    store_cache(String::from("starred"), data)
+}
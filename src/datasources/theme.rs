@@ -0,0 +1,29 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use ratatui::style::Color;
+
+use crate::entities::configuration::Theme;
+
+pub fn get_theme(theme: &Theme) -> Result<Theme, Box<dyn std::error::Error + Send + Sync>> {
+    let content = match theme.name.as_str() {
+        "dark" => include_str!("../../assets/themes/dark.toml"),
+        "light" => include_str!("../../assets/themes/light.toml"),
+        "solarized" => include_str!("../../assets/themes/solarized.toml"),
+        name => return Err(format!("Unknown theme: {}", name).into()),
+    };
+
+    let mut colors = toml::from_str::<HashMap<String, String>>(content)?;
+    colors.extend(theme.colors.clone());
+
+    for (key, value) in colors.iter() {
+        if Color::from_str(value).is_err() {
+            return Err(format!("Invalid color for theme.colors.{}: {}", key, value).into());
+        }
+    }
+
+    Ok(Theme {
+        name: theme.name.clone(),
+        colors,
+    })
+}
@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+
+use chrono::FixedOffset;
 use serde::Deserialize;
 
 #[derive(Deserialize, Debug, Clone)]
@@ -5,7 +8,13 @@ pub struct Configuration {
     pub with_default_config: bool,
     pub keymaps: KeyMaps,
     pub slack: Slack,
+    pub network: Network,
     pub status_line: StatusLine,
+    pub sidebar: Sidebar,
+    pub theme: Theme,
+    pub layout: Layout,
+    pub datetime: Datetime,
+    pub bindings: Bindings,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -17,9 +26,19 @@ pub struct KeyMaps {
     pub exit: String,
     pub open: String,
     pub search: String,
+    pub switcher: String,
     pub interact: String,
     pub send: String,
+    pub editor: String,
+    pub broadcast: String,
+    pub star: String,
+    pub top: String,
+    pub bottom: String,
+    pub links: String,
+    pub command: String,
+    pub timeout: u64,
     pub focus: KeyMapsFocus,
+    pub layout: KeyMapsLayout,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -30,10 +49,34 @@ pub struct KeyMapsFocus {
     pub right: String,
 }
 
+#[derive(Deserialize, Debug, Clone)]
+pub struct KeyMapsLayout {
+    pub grow: String,
+    pub shrink: String,
+    pub sidebar: String,
+    pub zoom: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Bindings {
+    pub global: HashMap<String, String>,
+    pub routes: HashMap<String, HashMap<String, String>>,
+    pub widgets: HashMap<String, HashMap<String, String>>,
+}
+
 #[derive(Deserialize, Debug, Clone)]
 pub struct Slack {
     pub client_id: String,
     pub client_secret: String,
+    pub user_scopes: Vec<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Network {
+    pub proxy: String,
+    pub no_proxy: Vec<String>,
+    pub ca_bundle: String,
+    pub timeout: u64,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -46,6 +89,91 @@ pub struct StatusLine {
 pub struct StatusLineSide {
     pub template: String,
     pub separator: String,
+    pub styles: Vec<StatusLineStyle>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct StatusLineStyle {
+    pub fg: Option<String>,
+    pub bg: Option<String>,
+    pub bold: Option<bool>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Sidebar {
+    pub groups: Vec<SidebarGroup>,
+    pub sort: SidebarSort,
+    pub order: Vec<String>,
+    pub muted: Vec<String>,
+    pub hidden: Vec<String>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum SidebarSort {
+    Recent,
+    Alphabetical,
+    Unread,
+    Manual,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct SidebarGroup {
+    pub name: String,
+    pub patterns: Vec<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Theme {
+    pub name: String,
+    pub colors: HashMap<String, String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Layout {
+    pub sidebar_width: u16,
+    pub sidebar_position: SidebarPosition,
+    pub thread_width: u16,
+    pub composer_height: u16,
+    pub status_line: StatusLinePosition,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum SidebarPosition {
+    Left,
+    Right,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum StatusLinePosition {
+    Top,
+    Bottom,
+    Hidden,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Datetime {
+    pub clock: Clock,
+    pub time_format: String,
+    pub time_format_12h: String,
+    pub date_format: String,
+    pub full_format: String,
+    pub relative_dates: bool,
+    pub relative_times: bool,
+    pub timezone: String,
+    // Parsed from `timezone` once the configuration is loaded, `None` meaning local time
+    #[serde(skip)]
+    pub offset: Option<FixedOffset>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub enum Clock {
+    #[serde(rename = "24h")]
+    Hour24,
+    #[serde(rename = "12h")]
+    Hour12,
 }
 
 impl Configuration {
@@ -64,8 +192,17 @@ impl Configuration {
                     exit: keymaps.exit.unwrap_or(self.keymaps.exit.clone()),
                     open: keymaps.open.unwrap_or(self.keymaps.open.clone()),
                     search: keymaps.search.unwrap_or(self.keymaps.search.clone()),
+                    switcher: keymaps.switcher.unwrap_or(self.keymaps.switcher.clone()),
                     interact: keymaps.interact.unwrap_or(self.keymaps.interact.clone()),
                     send: keymaps.send.unwrap_or(self.keymaps.send.clone()),
+                    editor: keymaps.editor.unwrap_or(self.keymaps.editor.clone()),
+                    broadcast: keymaps.broadcast.unwrap_or(self.keymaps.broadcast.clone()),
+                    star: keymaps.star.unwrap_or(self.keymaps.star.clone()),
+                    top: keymaps.top.unwrap_or(self.keymaps.top.clone()),
+                    bottom: keymaps.bottom.unwrap_or(self.keymaps.bottom.clone()),
+                    links: keymaps.links.unwrap_or(self.keymaps.links.clone()),
+                    command: keymaps.command.unwrap_or(self.keymaps.command.clone()),
+                    timeout: keymaps.timeout.unwrap_or(self.keymaps.timeout),
                     focus: keymaps
                         .focus
                         .map_or(self.keymaps.focus.clone(), |focus| KeyMapsFocus {
@@ -74,13 +211,32 @@ impl Configuration {
                             left: focus.left.unwrap_or(self.keymaps.focus.left.clone()),
                             right: focus.right.unwrap_or(self.keymaps.focus.right.clone()),
                         }),
+                    layout: keymaps
+                        .layout
+                        .map_or(self.keymaps.layout.clone(), |layout| KeyMapsLayout {
+                            grow: layout.grow.unwrap_or(self.keymaps.layout.grow.clone()),
+                            shrink: layout.shrink.unwrap_or(self.keymaps.layout.shrink.clone()),
+                            sidebar: layout
+                                .sidebar
+                                .unwrap_or(self.keymaps.layout.sidebar.clone()),
+                            zoom: layout.zoom.unwrap_or(self.keymaps.layout.zoom.clone()),
+                        }),
                 }),
             slack: other.slack.map_or(self.slack.clone(), |slack| Slack {
                 client_id: slack.client_id.unwrap_or(self.slack.client_id.clone()),
                 client_secret: slack
                     .client_secret
                     .unwrap_or(self.slack.client_secret.clone()),
+                user_scopes: slack.user_scopes.unwrap_or(self.slack.user_scopes.clone()),
             }),
+            network: other
+                .network
+                .map_or(self.network.clone(), |network| Network {
+                    proxy: network.proxy.unwrap_or(self.network.proxy.clone()),
+                    no_proxy: network.no_proxy.unwrap_or(self.network.no_proxy.clone()),
+                    ca_bundle: network.ca_bundle.unwrap_or(self.network.ca_bundle.clone()),
+                    timeout: network.timeout.unwrap_or(self.network.timeout),
+                }),
             status_line: other
                 .status_line
                 .map_or(self.status_line.clone(), |status_line| StatusLine {
@@ -93,6 +249,7 @@ impl Configuration {
                             separator: left
                                 .separator
                                 .unwrap_or(self.status_line.left.separator.clone()),
+                            styles: left.styles.unwrap_or(self.status_line.left.styles.clone()),
                         }),
                     right: status_line
                         .right
@@ -103,6 +260,85 @@ impl Configuration {
                             separator: right
                                 .separator
                                 .unwrap_or(self.status_line.right.separator.clone()),
+                            styles: right
+                                .styles
+                                .unwrap_or(self.status_line.right.styles.clone()),
+                        }),
+                }),
+            sidebar: other
+                .sidebar
+                .map_or(self.sidebar.clone(), |sidebar| Sidebar {
+                    groups: sidebar.groups.unwrap_or(self.sidebar.groups.clone()),
+                    sort: sidebar.sort.unwrap_or(self.sidebar.sort.clone()),
+                    order: sidebar.order.unwrap_or(self.sidebar.order.clone()),
+                    muted: sidebar.muted.unwrap_or(self.sidebar.muted.clone()),
+                    hidden: sidebar.hidden.unwrap_or(self.sidebar.hidden.clone()),
+                }),
+            theme: other.theme.map_or(self.theme.clone(), |theme| Theme {
+                name: theme.name.unwrap_or(self.theme.name.clone()),
+                colors: theme.colors.map_or(self.theme.colors.clone(), |colors| {
+                    let mut merged = self.theme.colors.clone();
+                    merged.extend(colors);
+                    merged
+                }),
+            }),
+            layout: other.layout.map_or(self.layout.clone(), |layout| Layout {
+                sidebar_width: layout.sidebar_width.unwrap_or(self.layout.sidebar_width),
+                sidebar_position: layout
+                    .sidebar_position
+                    .unwrap_or(self.layout.sidebar_position.clone()),
+                thread_width: layout.thread_width.unwrap_or(self.layout.thread_width),
+                composer_height: layout
+                    .composer_height
+                    .unwrap_or(self.layout.composer_height),
+                status_line: layout
+                    .status_line
+                    .unwrap_or(self.layout.status_line.clone()),
+            }),
+            datetime: other
+                .datetime
+                .map_or(self.datetime.clone(), |datetime| Datetime {
+                    clock: datetime.clock.unwrap_or(self.datetime.clock.clone()),
+                    time_format: datetime
+                        .time_format
+                        .unwrap_or(self.datetime.time_format.clone()),
+                    time_format_12h: datetime
+                        .time_format_12h
+                        .unwrap_or(self.datetime.time_format_12h.clone()),
+                    date_format: datetime
+                        .date_format
+                        .unwrap_or(self.datetime.date_format.clone()),
+                    full_format: datetime
+                        .full_format
+                        .unwrap_or(self.datetime.full_format.clone()),
+                    relative_dates: datetime
+                        .relative_dates
+                        .unwrap_or(self.datetime.relative_dates),
+                    relative_times: datetime
+                        .relative_times
+                        .unwrap_or(self.datetime.relative_times),
+                    timezone: datetime.timezone.unwrap_or(self.datetime.timezone.clone()),
+                    offset: None,
+                }),
+            bindings: other
+                .bindings
+                .map_or(self.bindings.clone(), |bindings| Bindings {
+                    global: bindings
+                        .global
+                        .map_or(self.bindings.global.clone(), |global| {
+                            let mut merged = self.bindings.global.clone();
+                            merged.extend(global);
+                            merged
+                        }),
+                    routes: bindings
+                        .routes
+                        .map_or(self.bindings.routes.clone(), |routes| {
+                            merge_scopes(&self.bindings.routes, routes)
+                        }),
+                    widgets: bindings
+                        .widgets
+                        .map_or(self.bindings.widgets.clone(), |widgets| {
+                            merge_scopes(&self.bindings.widgets, widgets)
                         }),
                 }),
         }
@@ -114,7 +350,13 @@ pub struct PartialConfiguration {
     pub with_default_config: Option<bool>,
     pub keymaps: Option<PartialKeyMaps>,
     pub slack: Option<PartialSlack>,
+    pub network: Option<PartialNetwork>,
     pub status_line: Option<PartialStatusLine>,
+    pub sidebar: Option<PartialSidebar>,
+    pub theme: Option<PartialTheme>,
+    pub layout: Option<PartialLayout>,
+    pub datetime: Option<PartialDatetime>,
+    pub bindings: Option<PartialBindings>,
 }
 
 #[derive(Deserialize, Debug, Clone, PartialEq)]
@@ -126,9 +368,19 @@ pub struct PartialKeyMaps {
     pub exit: Option<String>,
     pub open: Option<String>,
     pub search: Option<String>,
+    pub switcher: Option<String>,
     pub interact: Option<String>,
     pub send: Option<String>,
+    pub editor: Option<String>,
+    pub broadcast: Option<String>,
+    pub star: Option<String>,
+    pub top: Option<String>,
+    pub bottom: Option<String>,
+    pub links: Option<String>,
+    pub command: Option<String>,
+    pub timeout: Option<u64>,
     pub focus: Option<PartialKeyMapsFocus>,
+    pub layout: Option<PartialKeyMapsLayout>,
 }
 
 #[derive(Deserialize, Debug, Clone, PartialEq)]
@@ -139,10 +391,34 @@ pub struct PartialKeyMapsFocus {
     pub right: Option<String>,
 }
 
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct PartialKeyMapsLayout {
+    pub grow: Option<String>,
+    pub shrink: Option<String>,
+    pub sidebar: Option<String>,
+    pub zoom: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct PartialBindings {
+    pub global: Option<HashMap<String, String>>,
+    pub routes: Option<HashMap<String, HashMap<String, String>>>,
+    pub widgets: Option<HashMap<String, HashMap<String, String>>>,
+}
+
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct PartialSlack {
     pub client_id: Option<String>,
     pub client_secret: Option<String>,
+    pub user_scopes: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct PartialNetwork {
+    pub proxy: Option<String>,
+    pub no_proxy: Option<Vec<String>>,
+    pub ca_bundle: Option<String>,
+    pub timeout: Option<u64>,
 }
 
 #[derive(Deserialize, Debug, Clone, PartialEq)]
@@ -155,6 +431,43 @@ pub struct PartialStatusLine {
 pub struct PartialStatusLineSide {
     template: Option<String>,
     separator: Option<String>,
+    styles: Option<Vec<StatusLineStyle>>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct PartialSidebar {
+    pub groups: Option<Vec<SidebarGroup>>,
+    pub sort: Option<SidebarSort>,
+    pub order: Option<Vec<String>>,
+    pub muted: Option<Vec<String>>,
+    pub hidden: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct PartialTheme {
+    pub name: Option<String>,
+    pub colors: Option<HashMap<String, String>>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct PartialLayout {
+    pub sidebar_width: Option<u16>,
+    pub sidebar_position: Option<SidebarPosition>,
+    pub thread_width: Option<u16>,
+    pub composer_height: Option<u16>,
+    pub status_line: Option<StatusLinePosition>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct PartialDatetime {
+    pub clock: Option<Clock>,
+    pub time_format: Option<String>,
+    pub time_format_12h: Option<String>,
+    pub date_format: Option<String>,
+    pub full_format: Option<String>,
+    pub relative_dates: Option<bool>,
+    pub relative_times: Option<bool>,
+    pub timezone: Option<String>,
 }
 
 impl PartialConfiguration {
@@ -163,15 +476,27 @@ impl PartialConfiguration {
             with_default_config: None,
             keymaps: None,
             slack: None,
+            network: None,
             status_line: None,
+            sidebar: None,
+            theme: None,
+            layout: None,
+            datetime: None,
+            bindings: None,
         }
     }
 
     pub fn is_empty(&self) -> bool {
-        self.with_default_config == None
-            && self.keymaps == None
-            && self.slack == None
-            && self.status_line == None
+        self.with_default_config.is_none()
+            && self.keymaps.is_none()
+            && self.slack.is_none()
+            && self.network.is_none()
+            && self.status_line.is_none()
+            && self.sidebar.is_none()
+            && self.theme.is_none()
+            && self.layout.is_none()
+            && self.datetime.is_none()
+            && self.bindings.is_none()
     }
 
     pub fn unwrap_all(&self) -> Configuration {
@@ -188,8 +513,17 @@ impl PartialConfiguration {
                     exit: keymaps.exit.unwrap(),
                     open: keymaps.open.unwrap(),
                     search: keymaps.search.unwrap(),
+                    switcher: keymaps.switcher.unwrap(),
                     interact: keymaps.interact.unwrap(),
                     send: keymaps.send.unwrap(),
+                    editor: keymaps.editor.unwrap(),
+                    broadcast: keymaps.broadcast.unwrap(),
+                    star: keymaps.star.unwrap(),
+                    top: keymaps.top.unwrap(),
+                    bottom: keymaps.bottom.unwrap(),
+                    links: keymaps.links.unwrap(),
+                    command: keymaps.command.unwrap(),
+                    timeout: keymaps.timeout.unwrap(),
                     focus: keymaps
                         .focus
                         .clone()
@@ -200,6 +534,16 @@ impl PartialConfiguration {
                             right: focus.right.unwrap(),
                         })
                         .unwrap(),
+                    layout: keymaps
+                        .layout
+                        .clone()
+                        .map(|layout| KeyMapsLayout {
+                            grow: layout.grow.unwrap(),
+                            shrink: layout.shrink.unwrap(),
+                            sidebar: layout.sidebar.unwrap(),
+                            zoom: layout.zoom.unwrap(),
+                        })
+                        .unwrap(),
                 })
                 .unwrap(),
             slack: self
@@ -208,6 +552,17 @@ impl PartialConfiguration {
                 .map(|slack| Slack {
                     client_id: slack.client_id.unwrap(),
                     client_secret: slack.client_secret.unwrap(),
+                    user_scopes: slack.user_scopes.unwrap(),
+                })
+                .unwrap(),
+            network: self
+                .network
+                .clone()
+                .map(|network| Network {
+                    proxy: network.proxy.unwrap(),
+                    no_proxy: network.no_proxy.unwrap(),
+                    ca_bundle: network.ca_bundle.unwrap(),
+                    timeout: network.timeout.unwrap(),
                 })
                 .unwrap(),
             status_line: self
@@ -220,6 +575,7 @@ impl PartialConfiguration {
                         .map(|left| StatusLineSide {
                             template: left.template.unwrap(),
                             separator: left.separator.unwrap(),
+                            styles: left.styles.unwrap_or_default(),
                         })
                         .unwrap(),
                     right: status_line
@@ -228,10 +584,81 @@ impl PartialConfiguration {
                         .map(|right| StatusLineSide {
                             template: right.template.unwrap(),
                             separator: right.separator.unwrap(),
+                            styles: right.styles.unwrap_or_default(),
                         })
                         .unwrap(),
                 })
                 .unwrap(),
+            sidebar: self
+                .sidebar
+                .clone()
+                .map(|sidebar| Sidebar {
+                    groups: sidebar.groups.unwrap(),
+                    sort: sidebar.sort.unwrap(),
+                    order: sidebar.order.unwrap(),
+                    muted: sidebar.muted.unwrap(),
+                    hidden: sidebar.hidden.unwrap(),
+                })
+                .unwrap(),
+            theme: self
+                .theme
+                .clone()
+                .map(|theme| Theme {
+                    name: theme.name.unwrap(),
+                    colors: theme.colors.unwrap_or_default(),
+                })
+                .unwrap(),
+            layout: self
+                .layout
+                .clone()
+                .map(|layout| Layout {
+                    sidebar_width: layout.sidebar_width.unwrap(),
+                    sidebar_position: layout.sidebar_position.unwrap(),
+                    thread_width: layout.thread_width.unwrap(),
+                    composer_height: layout.composer_height.unwrap(),
+                    status_line: layout.status_line.unwrap(),
+                })
+                .unwrap(),
+            datetime: self
+                .datetime
+                .clone()
+                .map(|datetime| Datetime {
+                    clock: datetime.clock.unwrap(),
+                    time_format: datetime.time_format.unwrap(),
+                    time_format_12h: datetime.time_format_12h.unwrap(),
+                    date_format: datetime.date_format.unwrap(),
+                    full_format: datetime.full_format.unwrap(),
+                    relative_dates: datetime.relative_dates.unwrap(),
+                    relative_times: datetime.relative_times.unwrap(),
+                    timezone: datetime.timezone.unwrap(),
+                    offset: None,
+                })
+                .unwrap(),
+            bindings: self.bindings.clone().map_or(
+                Bindings {
+                    global: HashMap::new(),
+                    routes: HashMap::new(),
+                    widgets: HashMap::new(),
+                },
+                |bindings| Bindings {
+                    global: bindings.global.unwrap_or_default(),
+                    routes: bindings.routes.unwrap_or_default(),
+                    widgets: bindings.widgets.unwrap_or_default(),
+                },
+            ),
         }
     }
 }
+
+fn merge_scopes(
+    defaults: &HashMap<String, HashMap<String, String>>,
+    other: HashMap<String, HashMap<String, String>>,
+) -> HashMap<String, HashMap<String, String>> {
+    let mut merged = defaults.clone();
+
+    for (scope, bindings) in other {
+        merged.entry(scope).or_default().extend(bindings);
+    }
+
+    merged
+}
@@ -35,6 +35,27 @@ pub struct Channel {
     pub priority: Option<f32>,
     pub user: Option<String>,
     pub is_user_deleted: Option<bool>,
+    pub unread_count_display: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct InfoResponse {
+    pub ok: bool,
+    pub channel: Option<ChannelInfo>,
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ChannelInfo {
+    pub id: String,
+    pub last_read: Option<String>,
+    pub unread_count_display: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct OpenResponse {
+    pub ok: bool,
+    pub channel: Option<Channel>,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq)]
@@ -0,0 +1,9 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ApiResponse {
+    pub ok: bool,
+    pub emoji: Option<HashMap<String, String>>,
+}
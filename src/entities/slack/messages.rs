@@ -1,4 +1,4 @@
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct ApiResponse {
@@ -7,6 +7,12 @@ pub struct ApiResponse {
     //pub response_metadata: ResponseMetadata,
 }
 
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PostResponse {
+    pub ok: bool,
+    pub error: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct ResponseMetadata {
     pub next_cursor: String,
@@ -33,6 +39,7 @@ pub struct Message {
     pub edited: Option<Edited>,
     pub last_read: Option<String>,
     pub reactions: Option<Vec<Reaction>>,
+    pub attachments: Option<Vec<Attachment>>,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq)]
@@ -41,6 +48,23 @@ pub struct Block {
     pub block_type: String,
     pub block_id: Option<String>,
     pub elements: Option<Vec<Element>>,
+    #[serde(default, deserialize_with = "lenient")]
+    pub text: Option<TextObject>,
+    #[serde(default, deserialize_with = "lenient")]
+    pub fields: Option<Vec<TextObject>>,
+    #[serde(default, deserialize_with = "lenient")]
+    pub accessory: Option<Element>,
+    pub alt_text: Option<String>,
+    pub image_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct TextObject {
+    #[serde(rename = "type", default)]
+    pub text_type: String,
+    #[serde(default)]
+    pub text: String,
+    pub emoji: Option<bool>,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq)]
@@ -48,12 +72,67 @@ pub struct Element {
     #[serde(rename = "type")]
     pub element_type: String,
     pub elements: Option<Vec<Element>>, // Nested elements
-    pub text: Option<String>,
+    pub text: Option<ElementText>,
     pub user_id: Option<String>,
-    pub emoji: Option<String>,
+    pub channel_id: Option<String>,
+    pub usergroup_id: Option<String>,
+    pub name: Option<String>,
+    pub emoji: Option<bool>,
     pub unicode: Option<String>,
     pub range: Option<String>,
     pub url: Option<String>,
+    pub style: Option<ElementStyle>,
+    pub indent: Option<usize>,
+    pub offset: Option<usize>,
+    pub alt_text: Option<String>,
+    pub image_url: Option<String>,
+    pub action_id: Option<String>,
+    pub value: Option<String>,
+}
+
+// Rich text elements carry a plain string, Block Kit elements (buttons) a text object
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum ElementText {
+    Plain(String),
+    Object(TextObject),
+}
+
+// Lists and buttons use a plain string ("ordered", "danger"), text elements use flags
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum ElementStyle {
+    Plain(String),
+    Text(TextStyle),
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct TextStyle {
+    pub bold: Option<bool>,
+    pub italic: Option<bool>,
+    pub strike: Option<bool>,
+    pub code: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct Attachment {
+    pub color: Option<String>,
+    pub fallback: Option<String>,
+    pub pretext: Option<String>,
+    pub author_name: Option<String>,
+    pub title: Option<String>,
+    pub title_link: Option<String>,
+    pub text: Option<String>,
+    pub fields: Option<Vec<AttachmentField>>,
+    pub footer: Option<String>,
+    pub blocks: Option<Vec<Block>>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct AttachmentField {
+    pub title: Option<String>,
+    pub value: Option<String>,
+    pub short: Option<bool>,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq)]
@@ -68,3 +147,22 @@ pub struct Reaction {
     pub users: Vec<String>,
     pub count: u32,
 }
+
+impl ElementText {
+    pub fn value(&self) -> String {
+        match self {
+            ElementText::Plain(text) => text.clone(),
+            ElementText::Object(object) => object.text.clone(),
+        }
+    }
+}
+
+// Unknown block shapes are dropped instead of failing the whole history
+fn lenient<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    let value = serde_json::Value::deserialize(deserializer)?;
+    Ok(serde_json::from_value(value).ok())
+}
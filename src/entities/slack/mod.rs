@@ -1,4 +1,6 @@
 pub mod authorization;
 pub mod conversations;
+pub mod emoji;
 pub mod messages;
+pub mod usergroups;
 pub mod users;
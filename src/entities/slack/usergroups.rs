@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ApiResponse {
+    pub ok: bool,
+    pub usergroups: Option<Vec<Usergroup>>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct Usergroup {
+    pub id: String,
+    pub team_id: Option<String>,
+    pub name: String,
+    pub handle: String,
+    pub description: Option<String>,
+}
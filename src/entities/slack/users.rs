@@ -42,7 +42,13 @@ pub struct MemberProfile {
     pub display_name_normalized: String,
     //pub fields: Option<serde_json::Value>,
     pub status_text: Option<String>,
-    //pub status_emoji: Option<String>,
+    pub status_emoji: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PresenceResponse {
+    pub ok: bool,
+    pub presence: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
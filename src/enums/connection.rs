@@ -0,0 +1,18 @@
+use std::fmt;
+
+#[derive(Clone, PartialEq)]
+pub enum Connection {
+    Connecting,
+    Online,
+    Offline,
+}
+
+impl fmt::Display for Connection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Connection::Connecting => write!(f, "connecting"),
+            Connection::Online => write!(f, "online"),
+            Connection::Offline => write!(f, "offline"),
+        }
+    }
+}
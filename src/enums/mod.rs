@@ -1,3 +1,4 @@
+pub mod connection;
 pub mod section;
 pub mod user_mode;
 pub mod widgets;
@@ -1,10 +1,9 @@
-use ratatui::style::Color;
-
 #[derive(Clone, PartialEq)]
 pub enum UserMode {
     Normal,
     Interact,
     Search,
+    Command,
 }
 
 impl UserMode {
@@ -13,13 +12,7 @@ impl UserMode {
             UserMode::Normal => "NORMAL".to_string(),
             UserMode::Interact => "INTERACT".to_string(),
             UserMode::Search => "SEARCH".to_string(),
-        }
-    }
-    pub fn to_color(&self) -> Color {
-        match self {
-            UserMode::Normal => Color::Green,
-            UserMode::Interact => Color::Blue,
-            UserMode::Search => Color::Yellow,
+            UserMode::Command => "COMMAND".to_string(),
         }
     }
 }
@@ -15,7 +15,9 @@ mod utils;
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let config = datasources::configuration::get_configuration()?;
+    let client = datasources::slack::client(&config.network)
+        .map_err(|error| format!("Invalid network configuration: {}", error))?;
 
-    app::main(config).await;
+    app::main(config, client).await;
     Ok(())
 }
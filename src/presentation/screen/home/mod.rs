@@ -1,7 +1,9 @@
-use crossterm::event::{self, KeyCode, KeyEvent, KeyModifiers};
+use std::cmp::min;
+
+use crossterm::event::{self, KeyEvent, KeyModifiers};
 use ratatui::{
-    layout::{Constraint, Direction, Layout},
-    widgets::{Block, BorderType, Borders},
+    layout::{Constraint, Direction, Layout, Rect},
+    widgets::Block,
     Frame,
 };
 
@@ -9,28 +11,47 @@ use crate::{
     cache::Cache,
     common::enums::request::Request,
     context::Context,
-    entities::configuration::Configuration,
-    presentation::widgets::{channels, status_line},
+    entities::configuration::{Configuration, SidebarPosition, StatusLinePosition},
+    enums::user_mode::UserMode,
+    presentation::widgets::{channels, input, messages, status_line, switcher, thread, which_key},
+    utils::{self, sequence::Binding},
 };
 
 use super::Screen;
 
+const SIDEBAR_MIN_WIDTH: u16 = 16;
+const SIDEBAR_MAX_WIDTH: u16 = 120;
+const SIDEBAR_STEP: u16 = 4;
+const THREAD_MIN_WIDTH: u16 = 20;
+const THREAD_MAX_WIDTH: u16 = 80;
+const THREAD_STEP: u16 = 5;
+const COMPOSER_MIN_HEIGHT: u16 = 1;
+const COMPOSER_MAX_HEIGHT: u16 = 40;
+
 pub fn get() -> Screen<'static> {
     Screen {
         commands,
         keymaps,
+        sequences,
         build,
     }
 }
 
-fn commands(_config: &Configuration, command: &String, context: &mut Context) -> Option<Request> {
+fn commands(_config: &Configuration, command: &str, context: &mut Context) -> Option<Request> {
+    if context.state.channel.switcher.is_some() {
+        return (switcher::get().commands)(_config, command, context);
+    }
+
     let focus_commands: Option<Request> = match context.focus_id.clone().as_str() {
         "channels" => (channels::get().commands)(_config, command, context),
+        "messages" => (messages::get().commands)(_config, command, context),
+        "thread" => (thread::get().commands)(_config, command, context),
+        "input" => (input::get().commands)(_config, command, context),
         _ => None,
     };
 
     focus_commands.or({
-        match command.as_str() {
+        match command {
             "back" => {
                 context.route_pop();
             }
@@ -38,6 +59,41 @@ fn commands(_config: &Configuration, command: &String, context: &mut Context) ->
                 let focus_id = command.replace("focus ", "");
                 context.set_focus(focus_id);
             }
+            "switcher open" => {
+                switcher::open(_config, context);
+            }
+            command if command.starts_with("switch ") => {
+                let channel_id = command.replace("switch ", "");
+                return switcher::switch(context, &channel_id);
+            }
+            command if command.starts_with("dm ") => {
+                let user_id = command.replace("dm ", "");
+                return Some(Request::OpenDirectMessage(user_id));
+            }
+            "toggle broadcast" if context.state.message.opened.is_some() => {
+                context.state.thread.broadcast = !context.state.thread.broadcast;
+            }
+            command if command.starts_with("move focus ") => {
+                let direction = command.replace("move focus ", "");
+                let columns = columns(_config, context);
+                if let Some(focus_id) = neighbour(&context.focus_id, &direction, &columns) {
+                    context.set_focus(focus_id.to_string());
+                }
+            }
+            "grow pane" => {
+                resize(_config, context, true);
+            }
+            "shrink pane" => {
+                resize(_config, context, false);
+            }
+            "toggle sidebar" => {
+                context.state.layout.sidebar_hidden = !context.state.layout.sidebar_hidden;
+                leave_sidebar(_config, context);
+            }
+            "toggle zoom" => {
+                context.state.layout.zoom = !context.state.layout.zoom;
+                leave_sidebar(_config, context);
+            }
             _ => {}
         }
         None
@@ -49,19 +105,73 @@ fn keymaps(
     event: &event::Event,
     _context: &mut Context,
 ) -> Option<String> {
+    if _context.state.channel.switcher.is_some() {
+        return (switcher::get().keymaps)(_config, event, _context);
+    }
+
     let focus_keymaps: Option<String> = match _context.focus_id.clone().as_str() {
         "channels" => (channels::get().keymaps)(_config, event, _context),
+        "messages" => (messages::get().keymaps)(_config, event, _context),
+        "thread" => (thread::get().keymaps)(_config, event, _context),
+        "input" => (input::get().keymaps)(_config, event, _context),
         _ => None,
     };
 
+    let focus_up = utils::keycode::from_string(_config.keymaps.focus.up.clone());
+    let focus_down = utils::keycode::from_string(_config.keymaps.focus.down.clone());
+    let focus_left = utils::keycode::from_string(_config.keymaps.focus.left.clone());
+    let focus_right = utils::keycode::from_string(_config.keymaps.focus.right.clone());
+    let search = utils::keycode::from_string(_config.keymaps.search.clone());
+    let open_switcher = utils::keycode::from_string(_config.keymaps.switcher.clone());
+    let grow = utils::keycode::from_string(_config.keymaps.layout.grow.clone());
+    let shrink = utils::keycode::from_string(_config.keymaps.layout.shrink.clone());
+    let sidebar = utils::keycode::from_string(_config.keymaps.layout.sidebar.clone());
+    let zoom = utils::keycode::from_string(_config.keymaps.layout.zoom.clone());
+    let broadcast = utils::keycode::from_string(_config.keymaps.broadcast.clone());
+
     focus_keymaps.or({
         if let event::Event::Key(KeyEvent {
             modifiers, code, ..
         }) = event
         {
-            match (modifiers, code) {
-                (&KeyModifiers::SHIFT, KeyCode::Char('Q')) => {
-                    return Some(String::from("back"));
+            // Chords also toggle while typing a reply
+            let chord = modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SUPER);
+            if (*modifiers, *code) == broadcast && (chord || _context.mode == UserMode::Normal) {
+                return Some(String::from("toggle broadcast"));
+            }
+
+            if _context.mode != UserMode::Normal {
+                return None;
+            }
+
+            match (*modifiers, *code) {
+                key if key == search || key == open_switcher => {
+                    return Some(String::from("switcher open"));
+                }
+                key if key == focus_up => {
+                    return Some(String::from("move focus up"));
+                }
+                key if key == focus_down => {
+                    return Some(String::from("move focus down"));
+                }
+                key if key == focus_left => {
+                    return Some(String::from("move focus left"));
+                }
+                key if key == focus_right => {
+                    return Some(String::from("move focus right"));
+                }
+                key if key == grow => {
+                    return Some(String::from("grow pane"));
+                }
+                key if key == shrink => {
+                    return Some(String::from("shrink pane"));
+                }
+                key if key == sidebar => {
+                    return Some(String::from("toggle sidebar"));
+                }
+                key if key == zoom => {
+                    return Some(String::from("toggle zoom"));
                 }
                 _ => {
                     if _context.focus_id.is_empty() {
@@ -74,6 +184,20 @@ fn keymaps(
     })
 }
 
+fn sequences(_config: &Configuration, _context: &Context) -> Vec<Binding> {
+    if _context.state.channel.switcher.is_some() {
+        return (switcher::get().sequences)(_config, _context);
+    }
+
+    match _context.focus_id.as_str() {
+        "channels" => (channels::get().sequences)(_config, _context),
+        "messages" => (messages::get().sequences)(_config, _context),
+        "thread" => (thread::get().sequences)(_config, _context),
+        "input" => (input::get().sequences)(_config, _context),
+        _ => Vec::new(),
+    }
+}
+
 fn build<'screen>(
     _config: &Configuration,
     frame: &mut Frame,
@@ -81,27 +205,214 @@ fn build<'screen>(
     _cache: &mut Cache<'screen>,
 ) {
     frame.render_widget(Block::default().title("Home"), frame.area());
-    let rects = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Min(1), Constraint::Length(1)])
-        .split(frame.area());
 
-    let status_rect = rects[1];
+    let (main_rect, status_rect) = match _config.layout.status_line {
+        StatusLinePosition::Top => {
+            let rects = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(1), Constraint::Min(1)])
+                .split(frame.area());
+            (rects[1], Some(rects[0]))
+        }
+        StatusLinePosition::Bottom => {
+            let rects = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(1), Constraint::Length(1)])
+                .split(frame.area());
+            (rects[0], Some(rects[1]))
+        }
+        StatusLinePosition::Hidden => (frame.area(), None),
+    };
+
+    let columns = columns(_config, _context);
+    let sidebar_width = min(
+        sidebar_width(_config, _context),
+        main_rect.width.saturating_sub(SIDEBAR_MIN_WIDTH),
+    );
+    let thread_width = thread_width(_config, _context);
+
+    let constraints: Vec<Constraint> = columns
+        .iter()
+        .map(|column| match *column {
+            "channels" => Constraint::Length(sidebar_width),
+            "thread" if !_context.state.layout.zoom => Constraint::Percentage(thread_width),
+            _ => Constraint::Min(1),
+        })
+        .collect();
+
+    let zoom_pane = if _context.is_focus(&String::from("messages")) {
+        "messages"
+    } else {
+        compose_column(&columns)
+    };
+
+    let rects: Vec<(&str, Rect)> = if _context.state.layout.zoom {
+        vec![(zoom_pane, main_rect)]
+    } else {
+        let rects = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(constraints)
+            .split(main_rect);
+        columns.iter().copied().zip(rects.iter().copied()).collect()
+    };
+
+    let compose = if _context.state.layout.zoom {
+        zoom_pane
+    } else {
+        compose_column(&columns)
+    };
+
+    for (column, rect) in rects {
+        let rect = if column == compose {
+            let max_height = composer_height(_config, _context);
+            let compose_rects = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Min(1),
+                    Constraint::Length(input::height(_config, _context, rect.width, max_height)),
+                ])
+                .split(rect);
+            (input::get().build)(_config, frame, _context, _cache, compose_rects[1]);
+            compose_rects[0]
+        } else {
+            rect
+        };
+
+        match column {
+            "channels" => (channels::get().build)(_config, frame, _context, _cache, rect),
+            "messages" => (messages::get().build)(_config, frame, _context, _cache, rect),
+            "thread" => (thread::get().build)(_config, frame, _context, _cache, rect),
+            _ => {}
+        }
+    }
+
+    if let Some(status_rect) = status_rect {
+        status_line::render(frame, status_rect, _config, _context);
+    }
+    let mut bindings = utils::sequence::bindings(_config, _context);
+    bindings.extend(sequences(_config, _context));
+    which_key::render(frame, main_rect, _config, _context, &bindings);
+    (switcher::get().build)(_config, frame, _context, _cache, frame.area());
+}
+
+fn columns(config: &Configuration, context: &Context) -> Vec<&'static str> {
+    let mut columns = vec!["messages"];
 
-    let rects = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Max(40), Constraint::Min(1)])
-        .split(rects[0]);
+    if context.state.message.opened.is_some() {
+        columns.push("thread");
+    }
 
-    let channel_rect = rects[0];
-    let message_rect = rects[1];
+    if !context.state.layout.sidebar_hidden && !context.state.layout.zoom {
+        match config.layout.sidebar_position {
+            SidebarPosition::Left => columns.insert(0, "channels"),
+            SidebarPosition::Right => columns.push("channels"),
+        }
+    }
 
-    let block = Block::default()
-        .borders(Borders::ALL)
-        .border_type(BorderType::Rounded);
+    columns
+}
 
-    frame.render_widget(block.clone().title("Messages"), message_rect);
+fn compose_column(columns: &[&'static str]) -> &'static str {
+    if columns.contains(&"thread") {
+        "thread"
+    } else {
+        "messages"
+    }
+}
 
-    (channels::get().build)(_config, frame, _context, _cache, channel_rect);
-    status_line::render(frame, status_rect, &_config, &_context);
+fn sidebar_width(config: &Configuration, context: &Context) -> u16 {
+    context
+        .state
+        .layout
+        .sidebar_width
+        .unwrap_or(config.layout.sidebar_width)
+}
+
+fn thread_width(config: &Configuration, context: &Context) -> u16 {
+    context
+        .state
+        .layout
+        .thread_width
+        .unwrap_or(config.layout.thread_width)
+}
+
+fn composer_height(config: &Configuration, context: &Context) -> u16 {
+    context
+        .state
+        .layout
+        .composer_height
+        .unwrap_or(config.layout.composer_height)
+}
+
+fn resize(config: &Configuration, context: &mut Context, grow: bool) {
+    let thread_open = context.state.message.opened.is_some();
+
+    let (pane, grow) = match context.focus_id.as_str() {
+        "messages" if thread_open => ("thread", !grow),
+        "messages" => ("channels", !grow),
+        focus_id => (focus_id, grow),
+    };
+
+    let step = |value: u16, step: u16, lower: u16, upper: u16| {
+        if grow {
+            value.saturating_add(step).clamp(lower, upper)
+        } else {
+            value.saturating_sub(step).clamp(lower, upper)
+        }
+    };
+
+    match pane {
+        "channels" => {
+            let width = sidebar_width(config, context);
+            context.state.layout.sidebar_width = Some(step(
+                width,
+                SIDEBAR_STEP,
+                SIDEBAR_MIN_WIDTH,
+                SIDEBAR_MAX_WIDTH,
+            ));
+        }
+        "thread" => {
+            let width = thread_width(config, context);
+            context.state.layout.thread_width =
+                Some(step(width, THREAD_STEP, THREAD_MIN_WIDTH, THREAD_MAX_WIDTH));
+        }
+        "input" => {
+            let height = composer_height(config, context);
+            context.state.layout.composer_height =
+                Some(step(height, 1, COMPOSER_MIN_HEIGHT, COMPOSER_MAX_HEIGHT));
+        }
+        _ => {}
+    }
+}
+
+fn leave_sidebar(config: &Configuration, context: &mut Context) {
+    let columns = columns(config, context);
+
+    if context.is_focus(&String::from("channels")) && !columns.contains(&"channels") {
+        context.set_focus(String::from("messages"));
+    }
+}
+
+fn neighbour(focus_id: &str, direction: &str, columns: &[&'static str]) -> Option<&'static str> {
+    let compose = compose_column(columns);
+
+    match (focus_id, direction) {
+        ("input", "up") => Some(compose),
+        ("messages" | "thread", "down") => Some("input"),
+        (_, "left" | "right") => {
+            let current = if focus_id == "input" {
+                compose
+            } else {
+                focus_id
+            };
+            let index = columns.iter().position(|column| *column == current)?;
+            let index = if direction == "left" {
+                index.checked_sub(1)?
+            } else {
+                index + 1
+            };
+            columns.get(index).copied()
+        }
+        _ => None,
+    }
 }
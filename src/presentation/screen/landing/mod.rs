@@ -1,13 +1,16 @@
 use crossterm::event::{self, KeyEvent};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout},
-    widgets::{Block, BorderType, Borders, Paragraph},
+    style::Style,
+    text::Line,
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
     Frame,
 };
 
 use crate::{
     cache::Cache, common::enums::request::Request, context::Context,
-    entities::configuration::Configuration,
+    entities::configuration::Configuration, presentation::widgets::common::theme,
+    utils::sequence::Binding,
 };
 
 use super::Screen;
@@ -16,13 +19,18 @@ pub fn get() -> Screen<'static> {
     Screen {
         commands,
         keymaps,
+        sequences,
         build,
     }
 }
 
-fn commands(config: &Configuration, command: &String, context: &mut Context) -> Option<Request> {
+fn sequences(_config: &Configuration, _context: &Context) -> Vec<Binding> {
+    Vec::new()
+}
+
+fn commands(config: &Configuration, command: &str, context: &mut Context) -> Option<Request> {
     let mut request: Option<Request> = None;
-    match command.as_str() {
+    match command {
         "exit" => {
             context.route_pop();
         }
@@ -38,6 +46,13 @@ fn commands(config: &Configuration, command: &String, context: &mut Context) ->
         "login_success" => {
             context.route_push(String::from("/home"));
         }
+        "reauthorize" => {
+            request = Some(Request::Reauthorization(String::from("login_success")));
+        }
+        "continue" if !context.state.global.missing_scopes.is_empty() => {
+            context.state.global.missing_scopes = Vec::new();
+            context.route_push(String::from("/home"));
+        }
         _ => {}
     }
 
@@ -81,9 +96,12 @@ fn build<'screen>(
         ])
         .split(frame.area());
 
+    let prompt_rect = chunks[2];
+
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_type(BorderType::Rounded);
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme::color(config, "border")));
 
     frame.render_widget(block, frame.area());
 
@@ -100,9 +118,33 @@ fn build<'screen>(
         Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme::color(config, "border")))
             .title("command:")
             .title_alignment(Alignment::Center),
     );
 
     frame.render_widget(command, chunks[1]);
+
+    let missing_scopes = &context.state.global.missing_scopes;
+
+    if !missing_scopes.is_empty() {
+        let rects = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Min(0),
+                Constraint::Percentage(40),
+                Constraint::Min(0),
+            ])
+            .split(prompt_rect);
+
+        let prompt = Paragraph::new(vec![
+            Line::from(format!("Missing scopes: {}", missing_scopes.join(", "))),
+            Line::from("Type `reauthorize` to grant them or `continue` to skip"),
+        ])
+        .style(Style::default().fg(theme::color(config, "warning")))
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+        frame.render_widget(prompt, rects[1]);
+    }
 }
@@ -6,15 +6,16 @@ use ratatui::Frame;
 
 use crate::{
     cache::Cache, common::enums::request::Request, context::Context,
-    entities::configuration::Configuration,
+    entities::configuration::Configuration, utils::sequence::Binding,
 };
 
 #[derive(Clone)]
 pub struct Screen<'screen> {
     pub commands:
-        fn(config: &Configuration, command: &String, context: &mut Context) -> Option<Request>,
+        fn(config: &Configuration, command: &str, context: &mut Context) -> Option<Request>,
     pub keymaps:
         fn(config: &Configuration, event: &event::Event, context: &mut Context) -> Option<String>,
+    pub sequences: fn(config: &Configuration, context: &Context) -> Vec<Binding>,
     pub build: fn(
         config: &Configuration,
         frame: &mut Frame,
@@ -1,11 +1,11 @@
-use std::cmp::{max, min};
+use std::cmp::{max, min, Reverse};
 
-use crossterm::event::{self, KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{self, KeyEvent};
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
+    layout::Rect,
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, List, ListItem},
+    widgets::{List, ListItem},
     Frame,
 };
 use regex::Regex;
@@ -14,76 +14,61 @@ use crate::{
     cache::Cache,
     common::{constants::icon, enums::request::Request},
     context::Context,
-    entities::{configuration::Configuration, slack::conversations::Channel},
-    enums::{user_mode::UserMode, widgets::Widgets},
-    utils,
+    entities::{
+        configuration::{Configuration, SidebarSort},
+        slack::conversations::Channel,
+    },
+    enums::widgets::Widgets,
+    states::{MessageState, ThreadState},
+    utils::{self, sequence::Binding},
 };
 
-use super::Widget;
+use super::{common, Widget};
+
+const STARRED: &str = "Starred";
+const CHANNELS: &str = "Channels";
+const DIRECT_MESSAGES: &str = "Direct messages";
+
+#[derive(Clone, PartialEq)]
+enum Row {
+    Section {
+        name: String,
+        count: usize,
+        collapsed: bool,
+    },
+    Channel(Box<Channel>),
+}
 
 pub fn get<'widget>() -> Widget<'widget> {
     Widget {
         commands,
         keymaps,
+        sequences,
         build,
     }
 }
 
-fn commands(_config: &Configuration, command: &String, context: &mut Context) -> Option<Request> {
+fn commands(_config: &Configuration, command: &str, context: &mut Context) -> Option<Request> {
     let move_up_re = Regex::new(r"^move up (\d+)$").unwrap();
     let move_down_re = Regex::new(r"^move down (\d+)$").unwrap();
 
-    let filtered_channels: Vec<&Channel> = context
-        .state
-        .channel
-        .channels
-        .iter()
-        .filter(|channel| {
-            channel
-                .name
-                .clone()
-                .unwrap_or(channel.id.clone())
-                .contains(&context.state.channel.search)
-        })
-        .collect();
-    let last_index = max(filtered_channels.len() as i32 - 1, 0) as usize;
+    let rows = rows(_config, context);
+    let last_index = rows.len().saturating_sub(1);
+    let current_index = selected_index(&rows, context);
 
-    if let Some(channel) = context.state.channel.selected.clone() {
-        if !filtered_channels.contains(&&channel) {
-            context.state.channel.selected = None;
-            context.state.channel.selected_index = None;
-        }
-    }
-
-    match command.as_str() {
+    match command {
         command if command.starts_with("move up ") => {
             if let Some(captures) = move_up_re.captures(command) {
                 let len: usize = captures
                     .get(1)
                     .map_or(0, |len| len.as_str().parse().unwrap());
 
-                let next_index = match context.state.channel.selected.clone() {
-                    Some(selected) => {
-                        let index = filtered_channels
-                            .iter()
-                            .position(|channel| channel == &&selected);
-
-                        match index {
-                            Some(index) => {
-                                if index == 0 {
-                                    last_index
-                                } else {
-                                    max(0, index as i32 - len as i32) as usize
-                                }
-                            }
-                            None => last_index,
-                        }
-                    }
-                    None => last_index,
+                let next_index = match current_index {
+                    Some(0) | None => last_index,
+                    Some(index) => max(0, index as i32 - len as i32) as usize,
                 };
 
-                context.state.channel.selected = Some(filtered_channels[next_index].clone());
-                context.state.channel.selected_index = Some(next_index);
+                select(context, &rows, next_index);
             }
         }
         command if command.starts_with("move down ") => {
@@ -92,77 +77,241 @@ fn commands(_config: &Configuration, command: &String, context: &mut Context) ->
                     .get(1)
                     .map_or(0, |len| len.as_str().parse().unwrap());
 
-                let next_index = match context.state.channel.selected.clone() {
-                    Some(selected) => {
-                        let index = filtered_channels
-                            .iter()
-                            .position(|channel| channel == &&selected);
-
-                        match index {
-                            Some(index) => {
-                                if index == last_index {
-                                    0
-                                } else {
-                                    min(last_index, index + len)
-                                }
-                            }
-                            None => 0,
-                        }
-                    }
+                let next_index = match current_index {
+                    Some(index) if index == last_index => 0,
+                    Some(index) => min(last_index, index + len),
                     None => 0,
                 };
 
-                context.state.channel.selected = Some(filtered_channels[next_index].clone());
-                context.state.channel.selected_index = Some(next_index);
+                select(context, &rows, next_index);
             }
         }
-        command if command.starts_with("open ") => {
-            let channel_id = command.replace("open ", "");
-
-            let channel = context
-                .state
-                .channel
-                .channels
-                .iter()
-                .find(|channel| channel.id == channel_id);
-
-            if let Some(channel) = channel {
-                context.state.channel.opened = Some(channel.clone());
-                return Some(Request::GetConversationHistory(channel_id));
+        "move top" => {
+            select(context, &rows, 0);
+        }
+        command if command.starts_with("toggle section ") => {
+            let name = command.replace("toggle section ", "");
+            context.state.channel.toggle_section(&name);
+
+            let rows = self::rows(_config, context);
+            if let Some(index) = rows.iter().position(|row| match row {
+                Row::Section { name: section, .. } => section == &name,
+                _ => false,
+            }) {
+                select(context, &rows, index);
             }
         }
+        command if command.starts_with("toggle star ") => {
+            let channel_id = command.replace("toggle star ", "");
+            context.state.channel.toggle_star(&channel_id);
+
+            let rows = self::rows(_config, context);
+            match selected_index(&rows, context) {
+                Some(index) => select(context, &rows, index),
+                None => {
+                    context.state.channel.selected = None;
+                    context.state.channel.selected_index = None;
+                }
+            }
+        }
+        command if command.starts_with("open ") => {
+            return open(context, &command.replace("open ", ""));
+        }
         _ => {}
     }
     None
 }
 
+pub fn open(context: &mut Context, channel_id: &str) -> Option<Request> {
+    let channel = context.state.channel.get_channel(channel_id.to_string())?;
+    let channel_state = &mut context.state.channel;
+
+    channel_state.opened = Some(channel);
+    channel_state.set_unread(channel_id, 0);
+    channel_state.set_mentions(channel_id, 0);
+    channel_state.recent.retain(|recent| recent != channel_id);
+    channel_state.recent.insert(0, channel_id.to_string());
+
+    context.state.message = MessageState::new();
+    context.state.thread = ThreadState::new();
+    context.state.input.open(Some(channel_id.to_string()));
+
+    Some(Request::GetConversationHistory(channel_id.to_string()))
+}
+
+fn rows(config: &Configuration, context: &Context) -> Vec<Row> {
+    let channel_state = &context.state.channel;
+
+    let groups: Vec<Vec<Regex>> = config
+        .sidebar
+        .groups
+        .iter()
+        .map(|group| common::channel::patterns(&group.patterns))
+        .collect();
+    let hidden = common::channel::patterns(&config.sidebar.hidden);
+    let muted = common::channel::muted(config);
+
+    let mut sections: Vec<(String, Vec<(Channel, String)>)> =
+        vec![(STARRED.to_string(), Vec::new())];
+    sections.extend(
+        config
+            .sidebar
+            .groups
+            .iter()
+            .map(|group| (group.name.clone(), Vec::new())),
+    );
+    sections.push((CHANNELS.to_string(), Vec::new()));
+    sections.push((DIRECT_MESSAGES.to_string(), Vec::new()));
+
+    let conversations = channel_state
+        .channels
+        .iter()
+        .chain(channel_state.direct_messages.iter());
+
+    for channel in conversations {
+        let title = common::channel::title(channel, context);
+
+        if common::channel::matches(channel, &title, &hidden) {
+            continue;
+        }
+
+        let index = if channel_state.is_starred(&channel.id) {
+            0
+        } else if let Some(group) = groups
+            .iter()
+            .position(|patterns| common::channel::matches(channel, &title, patterns))
+        {
+            group + 1
+        } else if channel.is_im || channel.is_mpim.unwrap_or(false) {
+            sections.len() - 1
+        } else {
+            sections.len() - 2
+        };
+
+        sections[index].1.push((channel.clone(), title));
+    }
+
+    let mut rows: Vec<Row> = Vec::new();
+
+    for (name, mut channels) in sections {
+        if channels.is_empty() {
+            continue;
+        }
+
+        sort(config, &muted, &mut channels);
+
+        let collapsed = channel_state.collapsed.contains(&name);
+
+        rows.push(Row::Section {
+            name,
+            count: channels.len(),
+            collapsed,
+        });
+
+        if !collapsed {
+            rows.extend(
+                channels
+                    .into_iter()
+                    .map(|(channel, _)| Row::Channel(Box::new(channel))),
+            );
+        }
+    }
+
+    rows
+}
+
+fn sort(config: &Configuration, muted: &[Regex], channels: &mut [(Channel, String)]) {
+    let order = common::channel::patterns(&config.sidebar.order);
+    let recent = |channel: &Channel| Reverse(channel.updated.unwrap_or(0));
+
+    match config.sidebar.sort {
+        SidebarSort::Recent => channels.sort_by_key(|(channel, _)| recent(channel)),
+        SidebarSort::Alphabetical => channels.sort_by_key(|(_, title)| title.to_lowercase()),
+        SidebarSort::Unread => channels.sort_by_key(|(channel, title)| {
+            (
+                common::channel::unread(channel, title, muted) == 0,
+                recent(channel),
+            )
+        }),
+        SidebarSort::Manual => channels.sort_by_key(|(channel, title)| {
+            (
+                order
+                    .iter()
+                    .position(|pattern| {
+                        common::channel::matches(channel, title, std::slice::from_ref(pattern))
+                    })
+                    .unwrap_or(order.len()),
+                recent(channel),
+            )
+        }),
+    }
+}
+
+fn selected_index(rows: &[Row], context: &Context) -> Option<usize> {
+    let channel_state = &context.state.channel;
+
+    match &channel_state.selected {
+        Some(selected) => rows.iter().position(|row| match row {
+            Row::Channel(channel) => channel.id == selected.id,
+            _ => false,
+        }),
+        None => channel_state
+            .selected_index
+            .filter(|index| *index < rows.len()),
+    }
+}
+
+fn select(context: &mut Context, rows: &[Row], index: usize) {
+    let channel_state = &mut context.state.channel;
+
+    channel_state.selected_index = rows.get(index).map(|_| index);
+    channel_state.selected = match rows.get(index) {
+        Some(Row::Channel(channel)) => Some(channel.as_ref().clone()),
+        _ => None,
+    };
+}
+
+pub fn sequences(_config: &Configuration, _context: &Context) -> Vec<Binding> {
+    vec![Binding::new(_config.keymaps.top.clone(), "move top")]
+}
+
 pub fn keymaps(
     _config: &Configuration,
     event: &event::Event,
-    _context: &mut Context,
+    context: &mut Context,
 ) -> Option<String> {
     let up = utils::keycode::from_string(_config.keymaps.up.clone());
     let down = utils::keycode::from_string(_config.keymaps.down.clone());
     let open = utils::keycode::from_string(_config.keymaps.open.clone());
+    let star = utils::keycode::from_string(_config.keymaps.star.clone());
 
     if let event::Event::Key(KeyEvent {
         modifiers, code, ..
     }) = event.clone()
     {
+        let rows = rows(_config, context);
+        let row = selected_index(&rows, context).and_then(|index| rows.get(index));
+
         match (modifiers, code) {
-            (KeyModifiers::SHIFT, KeyCode::Char('Q')) => {
-                return Some(String::from("back"));
-            }
             key if key == up => {
                 return Some(String::from("move up 1"));
             }
             key if key == down => {
                 return Some(String::from("move down 1"));
             }
-            key if key == open => {
-                if let Some(channel) = &_context.state.channel.selected {
+            key if key == open => match row {
+                Some(Row::Channel(channel)) => {
                     return Some(format!("open {}", channel.id));
                 }
+                Some(Row::Section { name, .. }) => {
+                    return Some(format!("toggle section {}", name));
+                }
+                None => {}
+            },
+            key if key == star => {
+                if let Some(Row::Channel(channel)) = row {
+                    return Some(format!("toggle star {}", channel.id));
+                }
             }
             _ => {}
         }
@@ -174,94 +323,129 @@ pub fn keymaps(
 pub fn build(
     _config: &Configuration,
     frame: &mut Frame,
-    _context: &Context,
+    context: &Context,
     _cache: &mut Cache,
     rect: Rect,
 ) {
-    let channel_state = _context.state.channel.clone();
-    let channels = channel_state.channels;
-
-    let is_focus = _context.is_focus(&String::from("channels"));
-
-    let show_search = is_focus && _context.mode == UserMode::Search;
-
-    let rects = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints(if show_search {
-            vec![Constraint::Min(3), Constraint::Length(3)]
-        } else {
-            vec![Constraint::Min(3)]
-        })
-        .split(rect);
+    let channel_state = &context.state.channel;
+    let rows = rows(_config, context);
 
-    let channels_rect = rects[0];
+    let height = min(rect.height as i32 - 2, rows.len() as i32);
+    let selected = selected_index(&rows, context);
+    let index = selected.unwrap_or_default() as i32;
 
-    let height = min(channels_rect.height as i32 - 2, channels.len() as i32);
-    let index = channel_state.selected_index.unwrap_or_default() as i32;
+    let muted = common::channel::muted(_config);
+    let mut list_item: Vec<ListItem> = Vec::new();
 
-    let visible_channels: Vec<Channel> = channels
+    for (row_index, row) in rows
         .iter()
+        .enumerate()
         .skip(max(index - height + 1, 0) as usize)
-        .take(height as usize)
-        .map(|channel| channel.clone())
-        .collect();
+        .take(max(height, 0) as usize)
+    {
+        let style = if selected == Some(row_index) {
+            Style::default()
+                .bg(common::theme::color(_config, "selection_bg"))
+                .fg(common::theme::color(_config, "selection_fg"))
+        } else {
+            Style::default()
+        };
 
-    let mut list_item: Vec<ListItem> = Vec::new();
+        let item = match row {
+            Row::Section {
+                name,
+                count,
+                collapsed,
+            } => {
+                let line = if *collapsed {
+                    format!("▸ {} ({})", name, count)
+                } else {
+                    format!("▾ {}", name)
+                };
 
-    for channel in visible_channels {
-        let mut style = Style::default();
+                Line::styled(
+                    line,
+                    Style::default()
+                        .fg(common::theme::color(_config, "sidebar_section"))
+                        .add_modifier(Modifier::BOLD),
+                )
+            }
+            Row::Channel(channel) => channel_line(_config, channel, context, &muted, _cache),
+        };
 
-        if channel_state
-            .selected
-            .clone()
-            .map_or(false, |selected| channel == selected)
-        {
-            style = style.bg(Color::Cyan).fg(Color::Black);
-        }
+        list_item.push(ListItem::new(item).style(style));
+    }
 
-        let cache_id = format!("channels.{}", channel.id.clone());
+    let count = channel_state.channels.len() + channel_state.direct_messages.len();
 
-        let item = if let Some(Widgets::Line(widget)) = _cache.widget.get(&cache_id) {
-            widget.clone()
-        } else {
-            let mut line = Line::default();
-
-            let prefix = if channel.is_im {
-                icon::USER
-            } else if channel.is_mpim.unwrap_or(false) {
-                icon::GROUP
-            } else if channel.is_private.unwrap_or(false) {
-                icon::LOCK
-            } else {
-                icon::HASHTAG
-            };
-
-            let title = if channel.is_im {
-                _context
-                    .state
-                    .global
-                    .get_user(channel.user.clone().unwrap())
-                    .map_or(channel.user.clone().unwrap(), |user| {
-                        user.profile.display_name
-                    })
-            } else {
-                channel.name.clone().unwrap_or(channel.id.clone())
-            };
+    let is_focus = context.is_focus(&String::from("channels"));
 
-            line.push_span(Span::from(format!(" {} {}", prefix, title)));
+    if let Widgets::Block(block) = common::block::build(_config, is_focus, &context.mode) {
+        let block = block.title(format!("Channels {}", count));
+        frame.render_widget(List::new(list_item).block(block), rect);
+    }
+}
 
-            _cache.widget.insert(cache_id, Widgets::Line(line.clone()));
+fn channel_line<'cache>(
+    config: &Configuration,
+    channel: &Channel,
+    context: &Context,
+    muted: &[Regex],
+    cache: &mut Cache<'cache>,
+) -> Line<'cache> {
+    let has_draft = context.state.input.has_draft(&channel.id);
+    let title = common::channel::title(channel, context);
+    let unread = common::channel::unread(channel, &title, muted);
+    let muted = common::channel::matches(channel, &title, muted);
+    let cache_id = format!(
+        "channels.{}.{}.{}.{}",
+        channel.id.clone(),
+        has_draft,
+        muted,
+        unread
+    );
+
+    if let Some(Widgets::Line(widget)) = cache.widget.get(&cache_id) {
+        return widget.clone();
+    }
 
-            line
-        };
+    let title = match common::channel::status(channel, context) {
+        Some(status) => format!("{} {}", title, status),
+        None => title,
+    };
+
+    let style = if muted {
+        Style::default().fg(common::theme::color(config, "sidebar_muted"))
+    } else if unread > 0 {
+        Style::default().add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+    };
+
+    let mut line = Line::default();
+
+    line.push_span(Span::styled(
+        format!("  {} {}", common::channel::icon(channel), title),
+        style,
+    ));
+
+    if has_draft {
+        line.push_span(Span::styled(
+            format!(" {}", icon::DRAFT),
+            Style::default().fg(common::theme::color(config, "dimmed")),
+        ));
+    }
 
-        list_item.push(ListItem::new(item).style(style));
+    if unread > 0 {
+        line.push_span(Span::styled(
+            format!(" {}", unread),
+            Style::default()
+                .fg(common::theme::color(config, "sidebar_unread_fg"))
+                .bg(common::theme::color(config, "sidebar_unread_bg")),
+        ));
     }
 
-    let block = Block::default()
-        .borders(Borders::ALL)
-        .border_type(BorderType::Rounded)
-        .title(format!("Channels {}", list_item.clone().len(),));
+    cache.widget.insert(cache_id, Widgets::Line(line.clone()));
 
-    frame.render_widget(List::new(list_item).block(block), rect);
+    line
 }
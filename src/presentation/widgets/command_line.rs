@@ -0,0 +1,615 @@
+use std::cmp::min;
+
+use crossterm::event::{self, KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Position, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, Paragraph},
+    Frame,
+};
+
+use crate::{
+    cache::Cache,
+    common::enums::request::Request,
+    context::Context,
+    entities::configuration::Configuration,
+    enums::user_mode::UserMode,
+    route,
+    states::{editor::Editor, Completion, CompletionItem},
+    utils::{self, sequence::Binding, wrap},
+};
+
+use super::{channels, common, input, messages, thread, Widget};
+
+pub const PREFIX: &str = "command line ";
+
+const COMPLETION_LIMIT: usize = 10;
+const HISTORY_LIMIT: usize = 100;
+const PANES: [&str; 4] = ["channels", "messages", "thread", "input"];
+const DIRECTIONS: [&str; 4] = ["up", "down", "left", "right"];
+
+#[derive(Clone, Copy, PartialEq)]
+enum Argument {
+    None,
+    Channel,
+    User,
+    Pane,
+    Direction,
+    Count,
+    Text,
+}
+
+const SCREEN: &[&str] = &[];
+// Side effects outside the context, indistinguishable from a no-op
+const EXTERNAL: [&str; 1] = ["open links"];
+const LISTS: &[&str] = &["messages", "channels", "thread"];
+const PANES_WITH_MESSAGES: &[&str] = &["messages", "thread"];
+
+// Commands handled by a widget go to the focused one among their targets, or the first
+const COMMANDS: [(&str, &str, Argument, &[&str]); 28] = [
+    ("/", "exit", Argument::None, SCREEN),
+    ("/", "login", Argument::None, SCREEN),
+    ("/", "reauthorize", Argument::None, SCREEN),
+    ("/", "continue", Argument::None, SCREEN),
+    ("/home", "back", Argument::None, SCREEN),
+    ("/home", "focus", Argument::Pane, SCREEN),
+    ("/home", "move focus", Argument::Direction, SCREEN),
+    ("/home", "switcher open", Argument::None, SCREEN),
+    ("/home", "switch", Argument::Channel, SCREEN),
+    ("/home", "dm", Argument::User, SCREEN),
+    ("/home", "toggle broadcast", Argument::None, SCREEN),
+    ("/home", "toggle sidebar", Argument::None, SCREEN),
+    ("/home", "toggle zoom", Argument::None, SCREEN),
+    ("/home", "grow pane", Argument::None, SCREEN),
+    ("/home", "shrink pane", Argument::None, SCREEN),
+    ("/home", "toggle section", Argument::Text, &["channels"]),
+    ("/home", "toggle star", Argument::Channel, &["channels"]),
+    ("/home", "open", Argument::Channel, &["channels"]),
+    ("/home", "move up", Argument::Count, LISTS),
+    ("/home", "move down", Argument::Count, LISTS),
+    ("/home", "move top", Argument::None, LISTS),
+    ("/home", "move bottom", Argument::None, PANES_WITH_MESSAGES),
+    ("/home", "open thread", Argument::None, &["messages"]),
+    ("/home", "close thread", Argument::None, &["thread"]),
+    ("/home", "open links", Argument::None, PANES_WITH_MESSAGES),
+    ("/home", "edit message", Argument::None, PANES_WITH_MESSAGES),
+    ("/home", "open editor", Argument::None, &["input"]),
+    ("/home", "send", Argument::None, &["input"]),
+];
+
+pub fn get<'widget>() -> Widget<'widget> {
+    Widget {
+        commands,
+        keymaps,
+        sequences,
+        build,
+    }
+}
+
+fn commands(_config: &Configuration, command: &str, context: &mut Context) -> Option<Request> {
+    let command = command.strip_prefix(PREFIX)?;
+    let command_line = &mut context.state.command_line;
+
+    if command == "open" {
+        command_line.editor = Some(Editor::new());
+        command_line.history_index = None;
+        command_line.completion = None;
+        command_line.error = None;
+        context.state.sequence.reset();
+        context.mode = UserMode::Command;
+        return None;
+    }
+
+    let editor = command_line.editor.as_mut()?;
+
+    match command {
+        "close" => {
+            close(context);
+            return None;
+        }
+        "accept" => {
+            let value = editor.value().trim().to_string();
+            close(context);
+
+            if value.is_empty() {
+                return None;
+            }
+
+            let history = &mut context.state.command_line.history;
+            history.retain(|entry| entry != &value);
+            history.push(value.clone());
+            if history.len() > HISTORY_LIMIT {
+                history.remove(0);
+            }
+
+            return match resolve(context, &value) {
+                Ok((command, targets)) => dispatch(_config, &command, targets, context),
+                Err(error) => {
+                    context.state.command_line.error = Some(error);
+                    None
+                }
+            };
+        }
+        "complete" => {
+            complete(context, true);
+            return None;
+        }
+        "complete back" => {
+            complete(context, false);
+            return None;
+        }
+        "history up" => {
+            let history = &command_line.history;
+            let index = match command_line.history_index {
+                Some(index) => index.saturating_sub(1),
+                None if history.is_empty() => return None,
+                None => history.len() - 1,
+            };
+
+            command_line.history_index = Some(index);
+            editor.set(history[index].clone());
+        }
+        "history down" => {
+            let index = command_line.history_index?;
+
+            if index + 1 < command_line.history.len() {
+                command_line.history_index = Some(index + 1);
+                editor.set(command_line.history[index + 1].clone());
+            } else {
+                command_line.history_index = None;
+                editor.clear();
+            }
+        }
+        "delete backward" if editor.is_empty() => {
+            close(context);
+            return None;
+        }
+        "delete backward" => editor.delete_backward(),
+        "delete forward" => editor.delete_forward(),
+        "kill word backward" => editor.kill_word_backward(),
+        "clear" => editor.clear(),
+        "cursor left" => editor.move_left(),
+        "cursor right" => editor.move_right(),
+        "cursor start" => editor.move_start(),
+        "cursor end" => editor.move_end(),
+        command if command.starts_with("insert ") => {
+            editor.insert(&command.replacen("insert ", "", 1));
+        }
+        _ => return None,
+    }
+
+    command_line.completion = None;
+    None
+}
+
+fn close(context: &mut Context) {
+    let command_line = &mut context.state.command_line;
+    command_line.editor = None;
+    command_line.history_index = None;
+    command_line.completion = None;
+    context.mode = UserMode::Normal;
+}
+
+fn dispatch(
+    config: &Configuration,
+    command: &str,
+    targets: &[&str],
+    context: &mut Context,
+) -> Option<Request> {
+    let target = targets
+        .iter()
+        .find(|target| context.focus_id == **target)
+        .or(targets.first());
+
+    let commands = match target {
+        Some(&"channels") => channels::get().commands,
+        Some(&"messages") => messages::get().commands,
+        Some(&"thread") => thread::get().commands,
+        Some(&"input") => input::get().commands,
+        _ => route::get(context.current_route()).commands,
+    };
+
+    let before = context.clone();
+    let request = commands(config, command, context);
+
+    if request.is_none() && *context == before && !EXTERNAL.contains(&command) {
+        context.state.command_line.error = Some(format!("Command had no effect: {}", command));
+    }
+
+    request
+}
+
+fn registered(
+    route: &str,
+) -> impl Iterator<Item = (&'static str, Argument, &'static [&'static str])> + '_ {
+    COMMANDS
+        .iter()
+        .filter(move |(command_route, _, _, _)| *command_route == route)
+        .map(|(_, name, argument, targets)| (*name, *argument, *targets))
+}
+
+fn split(
+    route: &str,
+    value: &str,
+) -> Option<(&'static str, Argument, &'static [&'static str], String)> {
+    registered(route)
+        .filter_map(|(name, argument, targets)| match value.strip_prefix(name) {
+            Some("") => Some((name, argument, targets, String::new())),
+            Some(rest) if rest.starts_with(' ') => {
+                Some((name, argument, targets, rest.trim().to_string()))
+            }
+            _ => None,
+        })
+        .max_by_key(|(name, _, _, _)| name.len())
+}
+
+fn resolve(context: &Context, value: &str) -> Result<(String, &'static [&'static str]), String> {
+    let Some((name, argument, targets, rest)) = split(context.current_route(), value) else {
+        return Err(format!("Unknown command: {}", value));
+    };
+
+    let resolved = match argument {
+        Argument::None if rest.is_empty() => return Ok((name.to_string(), targets)),
+        Argument::None => return Err(format!("Unexpected argument for {}: {}", name, rest)),
+        Argument::Count if rest.is_empty() => String::from("1"),
+        _ if rest.is_empty() => return Err(format!("Missing argument for {}", name)),
+        Argument::Count => rest
+            .parse::<usize>()
+            .map_err(|_| format!("Invalid count: {}", rest))?
+            .to_string(),
+        Argument::Pane => PANES
+            .iter()
+            .find(|pane| **pane == rest)
+            .map(|pane| pane.to_string())
+            .ok_or(format!("Unknown pane: {}", rest))?,
+        Argument::Direction => DIRECTIONS
+            .iter()
+            .find(|direction| **direction == rest)
+            .map(|direction| direction.to_string())
+            .ok_or(format!("Unknown direction: {}", rest))?,
+        Argument::Channel => {
+            let channel_name = rest.trim_start_matches('#');
+            let channel_state = &context.state.channel;
+            channel_state
+                .channels
+                .iter()
+                .chain(channel_state.direct_messages.iter())
+                .find(|channel| {
+                    channel.id == rest || common::channel::title(channel, context) == channel_name
+                })
+                .map(|channel| channel.id.clone())
+                .ok_or(format!("Unknown channel: {}", rest))?
+        }
+        Argument::User => {
+            let user_name = rest.trim_start_matches('@');
+            context
+                .state
+                .global
+                .members
+                .iter()
+                .find(|member| {
+                    member.id == rest
+                        || member.name == user_name
+                        || member.profile.display_name == user_name
+                })
+                .map(|member| member.id.clone())
+                .ok_or(format!("Unknown user: {}", rest))?
+        }
+        Argument::Text => rest,
+    };
+
+    Ok((format!("{} {}", name, resolved), targets))
+}
+
+fn candidates(context: &Context, argument: Argument) -> Vec<String> {
+    match argument {
+        Argument::Channel => {
+            let channel_state = &context.state.channel;
+            channel_state
+                .channels
+                .iter()
+                .chain(channel_state.direct_messages.iter())
+                .map(|channel| common::channel::title(channel, context))
+                .collect()
+        }
+        Argument::User => context
+            .state
+            .global
+            .members
+            .iter()
+            .filter(|member| !member.deleted)
+            .map(|member| {
+                if member.profile.display_name.is_empty() {
+                    member.name.clone()
+                } else {
+                    member.profile.display_name.clone()
+                }
+            })
+            .collect(),
+        Argument::Pane => PANES.iter().map(|pane| pane.to_string()).collect(),
+        Argument::Direction => DIRECTIONS
+            .iter()
+            .map(|direction| direction.to_string())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn items(context: &Context, value: &str) -> Vec<CompletionItem> {
+    let route = context.current_route();
+
+    let mut items: Vec<CompletionItem> = match split(route, value) {
+        Some((name, argument, _, rest)) if value.len() > name.len() => {
+            let rest = rest.to_lowercase();
+            candidates(context, argument)
+                .into_iter()
+                .filter(|candidate| candidate.to_lowercase().starts_with(&rest))
+                .map(|candidate| CompletionItem {
+                    value: format!("{} {}", name, candidate),
+                    label: candidate,
+                    detail: String::new(),
+                    indices: Vec::new(),
+                })
+                .collect()
+        }
+        _ => registered(route)
+            .filter(|(name, _, _)| name.starts_with(value))
+            .map(|(name, argument, _)| CompletionItem {
+                label: name.to_string(),
+                detail: hint(argument).to_string(),
+                value: if argument == Argument::None {
+                    name.to_string()
+                } else {
+                    format!("{} ", name)
+                },
+                indices: Vec::new(),
+            })
+            .collect(),
+    };
+
+    items.sort_by(|a, b| a.label.cmp(&b.label));
+    items.dedup_by(|a, b| a.value == b.value);
+    items.truncate(COMPLETION_LIMIT);
+    items
+}
+
+fn hint(argument: Argument) -> &'static str {
+    match argument {
+        Argument::None => "",
+        Argument::Channel => "<channel>",
+        Argument::User => "<user>",
+        Argument::Pane => "<pane>",
+        Argument::Direction => "<direction>",
+        Argument::Count => "[count]",
+        Argument::Text => "<text>",
+    }
+}
+
+fn complete(context: &mut Context, forward: bool) {
+    let items = match &context.state.command_line.editor {
+        Some(editor) => items(context, editor.value()),
+        None => return,
+    };
+    let command_line = &mut context.state.command_line;
+
+    let completion = match command_line.completion.take() {
+        Some(mut completion) => {
+            let len = completion.items.len();
+            completion.selected_index = if forward {
+                (completion.selected_index + 1) % len
+            } else {
+                (completion.selected_index + len - 1) % len
+            };
+            completion
+        }
+        None if items.is_empty() => return,
+        None => Completion {
+            items,
+            selected_index: 0,
+        },
+    };
+
+    let value = completion.items[completion.selected_index].value.clone();
+    if let Some(editor) = command_line.editor.as_mut() {
+        editor.set(value);
+    }
+
+    if completion.items.len() > 1 {
+        command_line.completion = Some(completion);
+    }
+}
+
+pub fn keymaps(
+    _config: &Configuration,
+    event: &event::Event,
+    _context: &mut Context,
+) -> Option<String> {
+    if let event::Event::Key(_) = event {
+        _context.state.command_line.error = None;
+        _context.state.global.error = None;
+    }
+
+    if !_context.state.command_line.is_open() {
+        let open = utils::keycode::from_string(_config.keymaps.command.clone());
+
+        return match event {
+            event::Event::Key(KeyEvent {
+                modifiers, code, ..
+            }) if _context.mode == UserMode::Normal
+                && !_context.state.sequence.is_pending()
+                && (*modifiers - KeyModifiers::SHIFT, *code) == open =>
+            {
+                Some(format!("{}open", PREFIX))
+            }
+            _ => None,
+        };
+    }
+
+    if let event::Event::Paste(text) = event {
+        return Some(format!(
+            "{}insert {}",
+            PREFIX,
+            text.replace(['\r', '\n'], " ")
+        ));
+    }
+
+    if let event::Event::Key(KeyEvent {
+        modifiers, code, ..
+    }) = event.clone()
+    {
+        let command = match (modifiers, code) {
+            (_, KeyCode::Esc) => "close",
+            (_, KeyCode::Enter) => "accept",
+            (_, KeyCode::Tab) => "complete",
+            (_, KeyCode::BackTab) => "complete back",
+            (_, KeyCode::Up) | (KeyModifiers::CONTROL, KeyCode::Char('p')) => "history up",
+            (_, KeyCode::Down) | (KeyModifiers::CONTROL, KeyCode::Char('n')) => "history down",
+            (_, KeyCode::Left) | (KeyModifiers::CONTROL, KeyCode::Char('b')) => "cursor left",
+            (_, KeyCode::Right) | (KeyModifiers::CONTROL, KeyCode::Char('f')) => "cursor right",
+            (_, KeyCode::Home) | (KeyModifiers::CONTROL, KeyCode::Char('a')) => "cursor start",
+            (_, KeyCode::End) | (KeyModifiers::CONTROL, KeyCode::Char('e')) => "cursor end",
+            (KeyModifiers::CONTROL, KeyCode::Char('u')) => "clear",
+            (KeyModifiers::CONTROL, KeyCode::Char('w')) => "kill word backward",
+            (_, KeyCode::Backspace) => "delete backward",
+            (_, KeyCode::Delete) => "delete forward",
+            (modifiers, KeyCode::Char(char))
+                if !modifiers.contains(KeyModifiers::CONTROL)
+                    || modifiers.contains(KeyModifiers::ALT) =>
+            {
+                return Some(format!("{}insert {}", PREFIX, char));
+            }
+            _ => return None,
+        };
+
+        return Some(format!("{}{}", PREFIX, command));
+    }
+
+    None
+}
+
+fn sequences(_config: &Configuration, _context: &Context) -> Vec<Binding> {
+    Vec::new()
+}
+
+pub fn build(
+    _config: &Configuration,
+    frame: &mut Frame,
+    _context: &Context,
+    _cache: &mut Cache,
+    rect: Rect,
+) {
+    if rect.height == 0 {
+        return;
+    }
+
+    let command_line = &_context.state.command_line;
+    let line_rect = Rect::new(rect.x, rect.y + rect.height - 1, rect.width, 1);
+
+    if let Some(error) = command_line
+        .error
+        .as_ref()
+        .or(_context.state.global.error.as_ref())
+    {
+        frame.render_widget(Clear, line_rect);
+        frame.render_widget(
+            Paragraph::new(Line::styled(
+                error.clone(),
+                Style::default().fg(common::theme::color(_config, "danger")),
+            )),
+            line_rect,
+        );
+        return;
+    }
+
+    let Some(editor) = &command_line.editor else {
+        return;
+    };
+
+    frame.render_widget(Clear, line_rect);
+    frame.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled(
+                ":",
+                Style::default().fg(common::theme::mode(_config, &_context.mode)),
+            ),
+            Span::raw(editor.value().to_string()),
+        ])),
+        line_rect,
+    );
+    frame.set_cursor_position(Position::new(
+        line_rect.x + 1 + wrap::width(editor.before_cursor()) as u16,
+        line_rect.y,
+    ));
+
+    if let Some(completion) = &command_line.completion {
+        completion_popup(_config, frame, completion, line_rect);
+    }
+}
+
+fn completion_popup(
+    config: &Configuration,
+    frame: &mut Frame,
+    completion: &Completion,
+    rect: Rect,
+) {
+    let detail = Style::default().fg(common::theme::color(config, "dimmed"));
+
+    let items: Vec<ListItem> = completion
+        .items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let mut spans = vec![Span::raw(item.label.clone())];
+
+            if !item.detail.is_empty() {
+                spans.push(Span::styled(format!("  {}", item.detail), detail));
+            }
+
+            let style = if index == completion.selected_index {
+                Style::default()
+                    .bg(common::theme::color(config, "selection_bg"))
+                    .fg(common::theme::color(config, "selection_fg"))
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+
+            ListItem::new(Line::from(spans)).style(style)
+        })
+        .collect();
+
+    let height = min(items.len() as u16 + 2, rect.y);
+    let popup = Rect::new(rect.x, rect.y - height, min(rect.width, 50), height);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(common::theme::color(config, "border")));
+
+    frame.render_widget(Clear, popup);
+    frame.render_widget(List::new(items).block(block), popup);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split;
+
+    #[test]
+    fn split_prefers_the_longest_command() {
+        let cases = [
+            ("open thread", Some(("open thread", String::new()))),
+            ("open general", Some(("open", String::from("general")))),
+            ("open  #random ", Some(("open", String::from("#random")))),
+            ("move up 3", Some(("move up", String::from("3")))),
+            ("send", Some(("send", String::new()))),
+            ("sendx", None),
+            ("login", None),
+        ];
+
+        for (value, expected) in cases {
+            let actual = split("/home", value).map(|(name, _, _, rest)| (name, rest));
+            assert_eq!(actual, expected, "{}", value);
+        }
+    }
+}
@@ -1,15 +1,20 @@
 use ratatui::{
-    style::{Color, Style},
+    style::Style,
     widgets::{Block, BorderType, Borders},
 };
 
-use crate::enums::{user_mode::UserMode, widgets::Widgets};
+use crate::{
+    entities::configuration::Configuration,
+    enums::{user_mode::UserMode, widgets::Widgets},
+};
+
+use super::theme;
 
-pub fn build(highlight: bool, mode: &UserMode) -> Widgets<'static> {
+pub fn build(config: &Configuration, highlight: bool, mode: &UserMode) -> Widgets<'static> {
     let style = if highlight {
-        Style::default().fg(mode.to_color())
+        Style::default().fg(theme::mode(config, mode))
     } else {
-        Style::default()
+        Style::default().fg(theme::color(config, "border"))
     };
 
     Widgets::Block(
@@ -0,0 +1,388 @@
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+
+use crate::{
+    entities::{
+        configuration::Configuration,
+        slack::messages::{Attachment, Block, Element, ElementStyle, Message, TextObject},
+    },
+    states::State,
+    utils,
+};
+
+use super::{rich_text, theme};
+
+pub fn build(
+    message: &Message,
+    config: &Configuration,
+    state: &State,
+    width: usize,
+) -> Vec<Line<'static>> {
+    let mut lines = blocks(
+        &message.blocks.clone().unwrap_or_default(),
+        config,
+        state,
+        width,
+    );
+
+    if lines.is_empty() {
+        let spans = rich_text::mrkdwn(
+            &message.text.clone().unwrap_or_default(),
+            config,
+            state,
+            Style::default(),
+        );
+        rich_text::paragraphs(&mut lines, spans, Span::raw(""), Span::raw(""), width);
+    }
+
+    for attachment in message.attachments.clone().unwrap_or_default() {
+        lines.extend(self::attachment(&attachment, config, state, width));
+    }
+
+    lines
+}
+
+pub fn links(message: &Message) -> Vec<String> {
+    let mut links: Vec<String> = Vec::new();
+
+    let attachments = message.attachments.clone().unwrap_or_default();
+    let blocks = message.blocks.iter().flatten().chain(
+        attachments
+            .iter()
+            .flat_map(|attachment| attachment.blocks.iter().flatten()),
+    );
+
+    for block in blocks {
+        let elements = block
+            .elements
+            .iter()
+            .flatten()
+            .chain(block.accessory.iter());
+
+        for element in elements {
+            if element.element_type == "button" {
+                if let Some(url) = element.url.clone() {
+                    links.push(url);
+                }
+            }
+        }
+    }
+
+    for attachment in attachments {
+        if let Some(url) = attachment.title_link {
+            links.push(url);
+        }
+    }
+
+    links
+}
+
+pub fn open_links(message: &Message) -> Result<(), String> {
+    let links = links(message);
+    if links.is_empty() {
+        return Err(String::from("No links in message"));
+    }
+
+    for link in links {
+        utils::browser::open(&link)
+            .map_err(|error| format!("Could not open {}: {}", link, error))?;
+    }
+
+    Ok(())
+}
+
+fn blocks(
+    blocks: &[Block],
+    config: &Configuration,
+    state: &State,
+    width: usize,
+) -> Vec<Line<'static>> {
+    let mut lines: Vec<Line<'static>> = Vec::new();
+
+    for block in blocks {
+        match block.block_type.as_str() {
+            "rich_text" => {
+                lines.extend(rich_text::build(
+                    &block.elements.clone().unwrap_or_default(),
+                    config,
+                    state,
+                    width,
+                ));
+            }
+            "header" => {
+                let style = Style::default().add_modifier(Modifier::BOLD);
+                if let Some(text) = &block.text {
+                    let spans = vec![Span::styled(text.text.clone(), style)];
+                    rich_text::paragraphs(&mut lines, spans, Span::raw(""), Span::raw(""), width);
+                }
+            }
+            "section" => {
+                if let Some(text) = &block.text {
+                    let spans = text_object(text, config, state, Style::default());
+                    rich_text::paragraphs(&mut lines, spans, Span::raw(""), Span::raw(""), width);
+                }
+                if let Some(fields) = &block.fields {
+                    lines.extend(self::fields(fields, config, state, width));
+                }
+                if let Some(accessory) = &block.accessory {
+                    if accessory.element_type == "button" {
+                        lines.push(Line::from(button(config, accessory)));
+                    }
+                }
+            }
+            "context" => {
+                let style = Style::default().fg(theme::color(config, "dimmed"));
+                let mut spans: Vec<Span<'static>> = Vec::new();
+
+                for (index, element) in block.elements.iter().flatten().enumerate() {
+                    if index > 0 {
+                        spans.push(Span::styled(" · ", style));
+                    }
+                    if element.element_type == "image" {
+                        let alt_text = element.alt_text.clone().unwrap_or_default();
+                        spans.push(Span::styled(format!("[{}]", alt_text), style));
+                    } else if let Some(text) = &element.text {
+                        spans.extend(rich_text::mrkdwn(&text.value(), config, state, style));
+                    }
+                }
+
+                rich_text::paragraphs(&mut lines, spans, Span::raw(""), Span::raw(""), width);
+            }
+            "divider" => {
+                lines.push(Line::styled(
+                    "─".repeat(width),
+                    Style::default().fg(theme::color(config, "dimmed")),
+                ));
+            }
+            "actions" => {
+                let mut spans: Vec<Span<'static>> = Vec::new();
+
+                for element in block.elements.iter().flatten() {
+                    if element.element_type == "button" {
+                        spans.push(button(config, element));
+                        spans.push(Span::raw(" "));
+                    }
+                }
+
+                rich_text::paragraphs(&mut lines, spans, Span::raw(""), Span::raw(""), width);
+            }
+            "image" => {
+                let alt_text = block.alt_text.clone().unwrap_or_default();
+                lines.push(Line::styled(
+                    format!("[image: {}]", alt_text),
+                    Style::default().fg(theme::color(config, "dimmed")),
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    lines
+}
+
+fn fields(
+    fields: &[TextObject],
+    config: &Configuration,
+    state: &State,
+    width: usize,
+) -> Vec<Line<'static>> {
+    let mut lines: Vec<Line<'static>> = Vec::new();
+    let column_width = width / 2;
+
+    for pair in fields.chunks(2) {
+        let columns: Vec<Vec<Line<'static>>> = pair
+            .iter()
+            .map(|field| {
+                let mut column: Vec<Line<'static>> = Vec::new();
+                let spans = text_object(field, config, state, Style::default());
+                rich_text::paragraphs(
+                    &mut column,
+                    spans,
+                    Span::raw(""),
+                    Span::raw(""),
+                    column_width.saturating_sub(1),
+                );
+                column
+            })
+            .collect();
+
+        let height = columns.iter().map(|column| column.len()).max().unwrap_or(0);
+
+        for index in 0..height {
+            let mut line = Line::default();
+
+            for column in columns.iter() {
+                let part = column.get(index).cloned().unwrap_or_default();
+                let padding = column_width.saturating_sub(part.width());
+                for span in part.spans {
+                    line.push_span(span);
+                }
+                line.push_span(Span::raw(" ".repeat(padding)));
+            }
+
+            lines.push(line);
+        }
+    }
+
+    lines
+}
+
+fn attachment(
+    attachment: &Attachment,
+    config: &Configuration,
+    state: &State,
+    width: usize,
+) -> Vec<Line<'static>> {
+    let mut lines: Vec<Line<'static>> = Vec::new();
+    let mut body: Vec<Line<'static>> = Vec::new();
+    let inner_width = width.saturating_sub(2);
+
+    if let Some(pretext) = &attachment.pretext {
+        let spans = rich_text::mrkdwn(pretext, config, state, Style::default());
+        rich_text::paragraphs(&mut lines, spans, Span::raw(""), Span::raw(""), width);
+    }
+
+    if let Some(author_name) = &attachment.author_name {
+        let style = Style::default().fg(theme::color(config, "secondary"));
+        body.push(Line::styled(author_name.clone(), style));
+    }
+
+    if let Some(title) = &attachment.title {
+        let mut style = Style::default().add_modifier(Modifier::BOLD);
+        if attachment.title_link.is_some() {
+            style = style
+                .fg(theme::color(config, "link"))
+                .add_modifier(Modifier::UNDERLINED);
+        }
+        let spans = vec![Span::styled(title.clone(), style)];
+        rich_text::paragraphs(&mut body, spans, Span::raw(""), Span::raw(""), inner_width);
+    }
+
+    if let Some(text) = &attachment.text {
+        let spans = rich_text::mrkdwn(text, config, state, Style::default());
+        rich_text::paragraphs(&mut body, spans, Span::raw(""), Span::raw(""), inner_width);
+    }
+
+    for field in attachment.fields.clone().unwrap_or_default() {
+        if let Some(title) = field.title {
+            let style = Style::default().add_modifier(Modifier::BOLD);
+            body.push(Line::styled(title, style));
+        }
+        if let Some(value) = field.value {
+            let spans = rich_text::mrkdwn(&value, config, state, Style::default());
+            rich_text::paragraphs(&mut body, spans, Span::raw(""), Span::raw(""), inner_width);
+        }
+    }
+
+    body.extend(blocks(
+        &attachment.blocks.clone().unwrap_or_default(),
+        config,
+        state,
+        inner_width,
+    ));
+
+    if let Some(footer) = &attachment.footer {
+        let style = Style::default().fg(theme::color(config, "dimmed"));
+        let spans = rich_text::mrkdwn(footer, config, state, style);
+        rich_text::paragraphs(&mut body, spans, Span::raw(""), Span::raw(""), inner_width);
+    }
+
+    if body.is_empty() {
+        if let Some(fallback) = &attachment.fallback {
+            let spans = rich_text::mrkdwn(fallback, config, state, Style::default());
+            rich_text::paragraphs(&mut body, spans, Span::raw(""), Span::raw(""), inner_width);
+        }
+    }
+
+    let bar = Span::styled("▌ ", Style::default().fg(color(config, &attachment.color)));
+
+    for line in body {
+        let mut spans = vec![bar.clone()];
+        spans.extend(line.spans);
+        lines.push(Line::from(spans));
+    }
+
+    lines
+}
+
+fn text_object(
+    text: &TextObject,
+    config: &Configuration,
+    state: &State,
+    base: Style,
+) -> Vec<Span<'static>> {
+    if text.text_type == "mrkdwn" {
+        rich_text::mrkdwn(&text.text, config, state, base)
+    } else {
+        vec![Span::styled(text.text.clone(), base)]
+    }
+}
+
+fn button(config: &Configuration, element: &Element) -> Span<'static> {
+    let label = element
+        .text
+        .clone()
+        .map_or(String::new(), |text| text.value());
+
+    let background = match &element.style {
+        Some(ElementStyle::Plain(style)) if style == "primary" => "success",
+        Some(ElementStyle::Plain(style)) if style == "danger" => "danger",
+        _ => "button_bg",
+    };
+    let style = Style::default()
+        .fg(theme::color(config, "button_fg"))
+        .bg(theme::color(config, background));
+
+    if element.url.is_some() {
+        Span::styled(format!(" {} ↗ ", label), style)
+    } else {
+        Span::styled(format!(" {} ", label), style)
+    }
+}
+
+fn color(config: &Configuration, color: &Option<String>) -> Color {
+    match color.clone().unwrap_or_default().as_str() {
+        "good" => theme::color(config, "success"),
+        "warning" => theme::color(config, "warning"),
+        "danger" => theme::color(config, "danger"),
+        value => rgb(value).unwrap_or(theme::color(config, "dimmed")),
+    }
+}
+
+// Attachment colors come from remote apps, so anything but `#rrggbb` is rejected
+fn rgb(value: &str) -> Option<Color> {
+    let hex = value.strip_prefix('#').unwrap_or(value);
+    if hex.len() != 6 || !hex.chars().all(|char| char.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let rgb = u32::from_str_radix(hex, 16).ok()?;
+    Some(Color::Rgb((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8))
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+
+    use super::rgb;
+
+    #[test]
+    fn rgb_parses_only_hex_colors() {
+        let cases = [
+            ("#36a64f", Some(Color::Rgb(0x36, 0xa6, 0x4f))),
+            ("FF0000", Some(Color::Rgb(0xff, 0, 0))),
+            ("#fff", None),
+            ("+12345", None),
+            ("aéé1", None),
+            ("#aéé1", None),
+            ("ééé", None),
+            ("", None),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(rgb(value), expected, "{}", value);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use regex::Regex;
+
+use crate::{
+    common::constants::icon,
+    context::Context,
+    entities::{
+        configuration::Configuration,
+        slack::{conversations::Channel, users::Member},
+    },
+    states::ChannelState,
+    utils,
+};
+
+pub fn icon(channel: &Channel) -> &'static str {
+    if channel.is_im {
+        icon::USER
+    } else if channel.is_mpim.unwrap_or(false) {
+        icon::GROUP
+    } else if channel.is_private.unwrap_or(false) {
+        icon::LOCK
+    } else {
+        icon::HASHTAG
+    }
+}
+
+pub fn title(channel: &Channel, context: &Context) -> String {
+    let global = &context.state.global;
+
+    if channel.is_im {
+        let user_id = channel.user.clone().unwrap_or(channel.id.clone());
+        return global
+            .get_user(user_id.clone())
+            .map_or(user_id, |user| name(&user));
+    }
+
+    let channel_name = channel.name.clone().unwrap_or(channel.id.clone());
+
+    if !channel.is_mpim.unwrap_or(false) {
+        return channel_name;
+    }
+
+    let self_name = context
+        .auth
+        .as_ref()
+        .and_then(|auth| global.get_user(auth.authed_user.id.clone()))
+        .map(|user| user.name);
+
+    let participants = channel_name.strip_prefix("mpdm-").unwrap_or(&channel_name);
+    let participants = participants
+        .rsplit_once('-')
+        .map_or(participants, |(participants, _)| participants);
+
+    participants
+        .split("--")
+        .filter(|username| Some(username.to_string()) != self_name)
+        .map(|username| {
+            global
+                .members
+                .iter()
+                .find(|member| member.name == username)
+                .map_or(username.to_string(), name)
+        })
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+pub fn status(channel: &Channel, context: &Context) -> Option<String> {
+    if !channel.is_im {
+        return None;
+    }
+
+    context
+        .state
+        .global
+        .get_user(channel.user.clone()?)?
+        .profile
+        .status_emoji
+        .filter(|status| !status.is_empty())
+        .map(|status| utils::emoji::replace(&status, &context.state.global.emojis))
+}
+
+pub fn patterns(patterns: &[String]) -> Vec<Regex> {
+    patterns
+        .iter()
+        .filter_map(|pattern| {
+            let expression = regex::escape(pattern)
+                .replace(r"\*", ".*")
+                .replace(r"\?", ".");
+
+            Regex::new(&format!("(?i)^{}$", expression)).ok()
+        })
+        .collect()
+}
+
+pub fn matches(channel: &Channel, title: &str, patterns: &[Regex]) -> bool {
+    patterns.iter().any(|pattern| {
+        pattern.is_match(&channel.id)
+            || pattern.is_match(title)
+            || channel
+                .name
+                .as_ref()
+                .is_some_and(|name| pattern.is_match(name))
+    })
+}
+
+pub fn muted(config: &Configuration) -> Vec<Regex> {
+    patterns(&config.sidebar.muted)
+}
+
+pub fn unread(channel: &Channel, title: &str, muted: &[Regex]) -> u32 {
+    if matches(channel, title, muted) {
+        0
+    } else {
+        channel.unread_count_display.unwrap_or(0)
+    }
+}
+
+// Every direct message counts as a mention, channels count messages naming the user
+pub fn mentions(channel: &Channel, title: &str, muted: &[Regex], state: &ChannelState) -> u32 {
+    if channel.is_im || channel.is_mpim.unwrap_or(false) {
+        unread(channel, title, muted)
+    } else if matches(channel, title, muted) {
+        0
+    } else {
+        state.mentions.get(&channel.id).copied().unwrap_or(0)
+    }
+}
+
+fn name(user: &Member) -> String {
+    if user.profile.display_name.is_empty() {
+        user.real_name.clone().unwrap_or(user.name.clone())
+    } else {
+        user.profile.display_name.clone()
+    }
+}
@@ -0,0 +1,53 @@
+use std::cmp::min;
+
+use regex::Regex;
+
+// Selection after a move command, `Some(None)` leaving the list at its bottom
+pub fn moved(command: &str, selected_index: Option<usize>, len: usize) -> Option<Option<usize>> {
+    let move_re = Regex::new(r"^move (up|down) (\d+)$").unwrap();
+
+    if command == "move bottom" {
+        return Some(None);
+    }
+
+    let last_index = len.checked_sub(1)?;
+
+    if command == "move top" {
+        return Some(Some(0));
+    }
+
+    let captures = move_re.captures(command)?;
+    let count: usize = captures[2].parse().unwrap_or(1);
+
+    match (&captures[1], selected_index) {
+        ("up", None) => Some(Some(last_index)),
+        ("up", Some(index)) => Some(Some(index.saturating_sub(count))),
+        ("down", Some(index)) => Some(Some(min(last_index, index.saturating_add(count)))),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::moved;
+
+    #[test]
+    fn moved_walks_up_from_the_bottom() {
+        let cases = [
+            ("move up 1", None, 5, Some(Some(4))),
+            ("move up 2", Some(3), 5, Some(Some(1))),
+            ("move up 9", Some(3), 5, Some(Some(0))),
+            ("move down 1", Some(3), 5, Some(Some(4))),
+            ("move down 9", Some(3), 5, Some(Some(4))),
+            ("move down 1", None, 5, None),
+            ("move top", None, 5, Some(Some(0))),
+            ("move top", None, 0, None),
+            ("move bottom", Some(2), 5, Some(None)),
+            ("open thread", Some(2), 5, None),
+        ];
+
+        for (command, selected_index, len, expected) in cases {
+            assert_eq!(moved(command, selected_index, len), expected, "{}", command);
+        }
+    }
+}
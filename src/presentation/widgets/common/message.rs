@@ -0,0 +1,173 @@
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+
+use crate::{
+    cache::Cache,
+    entities::{configuration::Configuration, slack::messages::Message},
+    states::State,
+    utils::{datetime, wrap},
+};
+
+use super::{block_kit, reactions, theme};
+
+const INDENT: &str = "  ";
+const CACHE_LIMIT: usize = 2000;
+
+pub fn build<'cache>(
+    message: &Message,
+    config: &Configuration,
+    state: &State,
+    cache: &mut Cache<'cache>,
+    width: usize,
+    selected: bool,
+) -> Vec<Line<'cache>> {
+    let time = datetime::parse(config, &message.ts).map_or(String::new(), |datetime| {
+        if selected {
+            datetime::full(config, &datetime)
+        } else {
+            datetime::relative_time(config, &datetime)
+        }
+    });
+    // One entry per message and width, replaced when the message or its time changes
+    let cache_id = format!("message.{}.{}", message.ts, width);
+    let fingerprint = format!("{}.{}", version(message), time);
+
+    if let Some((_, lines)) = cache
+        .messages
+        .get(&cache_id)
+        .filter(|(cached, _)| *cached == fingerprint)
+    {
+        return lines.clone();
+    }
+
+    let lines = render(message, config, state, width, &time);
+    if cache.messages.len() >= CACHE_LIMIT {
+        cache.messages.clear();
+    }
+    cache
+        .messages
+        .insert(cache_id, (fingerprint, lines.clone()));
+
+    lines
+}
+
+fn version(message: &Message) -> String {
+    let reactions: u32 = message
+        .reactions
+        .iter()
+        .flatten()
+        .map(|reaction| reaction.count)
+        .sum();
+
+    format!(
+        "{}.{}.{}.{}",
+        message.ts,
+        message
+            .edited
+            .clone()
+            .map_or(String::new(), |edited| edited.ts),
+        message.reply_count.unwrap_or_default(),
+        reactions
+    )
+}
+
+pub fn separator(config: &Configuration, date: &str, width: usize) -> Line<'static> {
+    let dashes = width.saturating_sub(wrap::width(date) + 2);
+    let left = dashes / 2;
+
+    Line::styled(
+        format!(
+            "{} {} {}",
+            "─".repeat(left),
+            date,
+            "─".repeat(dashes - left)
+        ),
+        Style::default().fg(theme::color(config, "separator")),
+    )
+}
+
+fn render(
+    message: &Message,
+    config: &Configuration,
+    state: &State,
+    width: usize,
+    time: &str,
+) -> Vec<Line<'static>> {
+    let user_id = message
+        .user
+        .clone()
+        .unwrap_or(message.bot_id.clone().unwrap_or_default());
+    let user = state.global.get_user(user_id.clone());
+
+    let user_name = user.clone().map_or(user_id, |user| {
+        if user.profile.display_name.is_empty() {
+            user.real_name.unwrap_or(user.name)
+        } else {
+            user.profile.display_name
+        }
+    });
+    let user_color = user
+        .and_then(|user| user.color)
+        .filter(|color| color.len() == 6)
+        .map_or(theme::color(config, "text"), |color| {
+            let r = u8::from_str_radix(&color[0..2], 16).unwrap_or(255);
+            let g = u8::from_str_radix(&color[2..4], 16).unwrap_or(255);
+            let b = u8::from_str_radix(&color[4..6], 16).unwrap_or(255);
+            Color::Rgb(r, g, b)
+        });
+
+    let mut header = vec![
+        Span::styled(
+            user_name,
+            Style::default().fg(user_color).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!(" {}", time),
+            Style::default().fg(theme::color(config, "timestamp")),
+        ),
+    ];
+
+    if message.edited.is_some() {
+        header.push(Span::styled(
+            " (edited)",
+            Style::default().fg(theme::color(config, "timestamp")),
+        ));
+    }
+
+    let mut lines = vec![Line::from(header)];
+
+    let body_width = width.saturating_sub(INDENT.len());
+    for line in block_kit::build(message, config, state, body_width) {
+        let mut spans = vec![Span::raw(INDENT)];
+        spans.extend(line.spans);
+        lines.push(Line::from(spans));
+    }
+
+    let mut footer = reactions::build(
+        &message.reactions.clone().unwrap_or_default(),
+        config,
+        state,
+    );
+
+    if let Some(count) = message.reply_count.filter(|count| *count > 0) {
+        if !footer.is_empty() {
+            footer.push(Span::raw(" "));
+        }
+        footer.push(Span::styled(
+            format!("{} {}", count, if count == 1 { "reply" } else { "replies" }),
+            Style::default().fg(theme::color(config, "reply_count")),
+        ));
+    }
+
+    if !footer.is_empty() {
+        for part in wrap::wrap_spans(footer, body_width) {
+            let mut spans = vec![Span::raw(INDENT)];
+            spans.extend(part);
+            lines.push(Line::from(spans));
+        }
+    }
+
+    lines
+}
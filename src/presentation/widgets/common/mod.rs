@@ -1 +1,8 @@
 pub mod block;
+pub mod block_kit;
+pub mod channel;
+pub mod list;
+pub mod message;
+pub mod reactions;
+pub mod rich_text;
+pub mod theme;
@@ -0,0 +1,30 @@
+use ratatui::{style::Style, text::Span};
+
+use crate::{
+    entities::{configuration::Configuration, slack::messages::Reaction},
+    states::State,
+    utils::emoji,
+};
+
+use super::theme;
+
+pub fn build(reactions: &[Reaction], config: &Configuration, state: &State) -> Vec<Span<'static>> {
+    let style = Style::default().fg(theme::color(config, "reaction"));
+    let mut spans: Vec<Span<'static>> = Vec::new();
+
+    for (index, reaction) in reactions.iter().enumerate() {
+        if index > 0 {
+            spans.push(Span::styled(" ", style));
+        }
+        spans.push(Span::styled(
+            format!(
+                "[{} {}]",
+                emoji::get(&reaction.name, &state.global.emojis),
+                reaction.count
+            ),
+            style,
+        ));
+    }
+
+    spans
+}
@@ -0,0 +1,365 @@
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+use regex::Regex;
+
+use crate::{
+    entities::{
+        configuration::Configuration,
+        slack::messages::{Element, ElementStyle, TextStyle},
+    },
+    states::State,
+    utils::{emoji, wrap::wrap_spans},
+};
+
+use super::theme;
+
+const BULLETS: [&str; 3] = ["•", "◦", "▪"];
+
+pub fn build(
+    elements: &[Element],
+    config: &Configuration,
+    state: &State,
+    width: usize,
+) -> Vec<Line<'static>> {
+    let mut lines: Vec<Line<'static>> = Vec::new();
+
+    for element in elements {
+        let children: Vec<Element> = element.elements.clone().unwrap_or_default();
+
+        match element.element_type.as_str() {
+            "rich_text_section" => {
+                let spans = inline(&children, config, state, Style::default());
+                paragraphs(&mut lines, spans, Span::raw(""), Span::raw(""), width);
+            }
+            "rich_text_list" => {
+                let indent = element.indent.unwrap_or_default();
+                let ordered = element.style == Some(ElementStyle::Plain(String::from("ordered")));
+                let padding = "  ".repeat(indent);
+
+                for (index, item) in children.iter().enumerate() {
+                    let marker = if ordered {
+                        format!("{}. ", element.offset.unwrap_or_default() + index + 1)
+                    } else {
+                        format!("{} ", BULLETS[indent % BULLETS.len()])
+                    };
+                    let next_prefix = " ".repeat(padding.len() + marker.chars().count());
+
+                    let spans = inline(
+                        &item.elements.clone().unwrap_or_default(),
+                        config,
+                        state,
+                        Style::default(),
+                    );
+                    paragraphs(
+                        &mut lines,
+                        spans,
+                        Span::raw(format!("{}{}", padding, marker)),
+                        Span::raw(next_prefix),
+                        width,
+                    );
+                }
+            }
+            "rich_text_quote" => {
+                let gutter = Span::styled("▎ ", Style::default().fg(theme::color(config, "quote")));
+                let spans = inline(&children, config, state, Style::default());
+                paragraphs(&mut lines, spans, gutter.clone(), gutter, width);
+            }
+            "rich_text_preformatted" => {
+                let style = Style::default().bg(theme::color(config, "code_bg"));
+                let spans = inline(&children, config, state, style);
+                let start = lines.len();
+
+                paragraphs(
+                    &mut lines,
+                    spans,
+                    Span::styled(" ", style),
+                    Span::styled(" ", style),
+                    width,
+                );
+
+                for line in lines.iter_mut().skip(start) {
+                    let padding = width.saturating_sub(line.width());
+                    line.push_span(Span::styled(" ".repeat(padding), style));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    lines
+}
+
+pub fn paragraphs(
+    lines: &mut Vec<Line<'static>>,
+    spans: Vec<Span<'static>>,
+    first_prefix: Span<'static>,
+    next_prefix: Span<'static>,
+    width: usize,
+) {
+    let prefix_width = first_prefix.width().max(next_prefix.width());
+    let mut first = true;
+
+    for part in wrap_spans(spans, width.saturating_sub(prefix_width)) {
+        let prefix = if first {
+            first_prefix.clone()
+        } else {
+            next_prefix.clone()
+        };
+        first = false;
+
+        let mut line = Line::from(prefix);
+        for span in part {
+            line.push_span(span);
+        }
+        lines.push(line);
+    }
+}
+
+fn inline(
+    elements: &[Element],
+    config: &Configuration,
+    state: &State,
+    base: Style,
+) -> Vec<Span<'static>> {
+    let mention = Style::default()
+        .fg(theme::color(config, "mention"))
+        .add_modifier(Modifier::BOLD);
+    let link = Style::default()
+        .fg(theme::color(config, "link"))
+        .add_modifier(Modifier::UNDERLINED);
+
+    let mut spans: Vec<Span<'static>> = Vec::new();
+
+    for element in elements {
+        let style = match &element.style {
+            Some(ElementStyle::Text(text_style)) => {
+                base.patch(text_style_to_style(config, text_style))
+            }
+            _ => base,
+        };
+
+        let span = match element.element_type.as_str() {
+            "text" => Span::styled(text(element), style),
+            "link" => Span::styled(
+                element
+                    .text
+                    .clone()
+                    .map_or(element.url.clone().unwrap_or_default(), |text| text.value()),
+                style.patch(link),
+            ),
+            "user" => {
+                let user_id = element.user_id.clone().unwrap_or_default();
+                let name = state
+                    .global
+                    .get_user(user_id.clone())
+                    .map_or(user_id, |user| user.profile.display_name);
+                Span::styled(format!("@{}", name), style.patch(mention))
+            }
+            "channel" => {
+                let channel_id = element.channel_id.clone().unwrap_or_default();
+                let name = state
+                    .channel
+                    .get_channel(channel_id.clone())
+                    .and_then(|channel| channel.name)
+                    .unwrap_or(channel_id);
+                Span::styled(format!("#{}", name), style.patch(mention))
+            }
+            "usergroup" => {
+                let usergroup_id = element.usergroup_id.clone().unwrap_or_default();
+                let name = state
+                    .global
+                    .get_usergroup(usergroup_id.clone())
+                    .map_or(usergroup_id, |usergroup| usergroup.handle);
+                Span::styled(format!("@{}", name), style.patch(mention))
+            }
+            "broadcast" => Span::styled(
+                format!("@{}", element.range.clone().unwrap_or_default()),
+                style.patch(mention),
+            ),
+            "emoji" => Span::styled(self::emoji(element, state), style),
+            _ => Span::styled(text(element), style),
+        };
+
+        spans.push(span);
+    }
+
+    spans
+}
+
+fn text(element: &Element) -> String {
+    element
+        .text
+        .clone()
+        .map_or(String::new(), |text| text.value())
+}
+
+fn text_style_to_style(config: &Configuration, text_style: &TextStyle) -> Style {
+    let mut style = Style::default();
+
+    if text_style.bold.unwrap_or(false) {
+        style = style.add_modifier(Modifier::BOLD);
+    }
+    if text_style.italic.unwrap_or(false) {
+        style = style.add_modifier(Modifier::ITALIC);
+    }
+    if text_style.strike.unwrap_or(false) {
+        style = style.add_modifier(Modifier::CROSSED_OUT);
+    }
+    if text_style.code.unwrap_or(false) {
+        style = style
+            .fg(theme::color(config, "code_fg"))
+            .bg(theme::color(config, "code_bg"));
+    }
+
+    style
+}
+
+fn emoji(element: &Element, state: &State) -> String {
+    let name = element.name.clone().unwrap_or_default();
+
+    element
+        .unicode
+        .clone()
+        .and_then(|unicode| {
+            unicode
+                .split('-')
+                .map(|code| u32::from_str_radix(code, 16).ok().and_then(char::from_u32))
+                .collect::<Option<String>>()
+        })
+        .unwrap_or_else(|| emoji::get(&name, &state.global.emojis))
+}
+
+pub fn mrkdwn(
+    text: &str,
+    config: &Configuration,
+    state: &State,
+    base: Style,
+) -> Vec<Span<'static>> {
+    let re = Regex::new(r"<([^>]+)>").unwrap();
+    let mention = base
+        .fg(theme::color(config, "mention"))
+        .add_modifier(Modifier::BOLD);
+    let link = base
+        .fg(theme::color(config, "link"))
+        .add_modifier(Modifier::UNDERLINED);
+
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut last = 0;
+
+    for captures in re.captures_iter(text) {
+        let whole = captures.get(0).unwrap();
+        let inner = &captures[1];
+        let (target, label) = match inner.split_once('|') {
+            Some((target, label)) => (target, Some(label.to_string())),
+            None => (inner, None),
+        };
+
+        spans.extend(formatting(&text[last..whole.start()], config, state, base));
+        last = whole.end();
+
+        let span = if let Some(user_id) = target.strip_prefix('@') {
+            let name = state
+                .global
+                .get_user(user_id.to_string())
+                .map_or(label.unwrap_or(user_id.to_string()), |user| {
+                    user.profile.display_name
+                });
+            Span::styled(format!("@{}", name), mention)
+        } else if let Some(channel_id) = target.strip_prefix('#') {
+            let name = state
+                .channel
+                .get_channel(channel_id.to_string())
+                .and_then(|channel| channel.name)
+                .or(label)
+                .unwrap_or(channel_id.to_string());
+            Span::styled(format!("#{}", name), mention)
+        } else if let Some(special) = target.strip_prefix('!') {
+            let name = label.unwrap_or(match special.split_once('^') {
+                Some((_, id)) => format!(
+                    "@{}",
+                    state
+                        .global
+                        .get_usergroup(id.to_string())
+                        .map_or(id.to_string(), |usergroup| usergroup.handle)
+                ),
+                None => format!("@{}", special),
+            });
+            Span::styled(name, mention)
+        } else {
+            Span::styled(unescape(&label.unwrap_or(target.to_string())), link)
+        };
+
+        spans.push(span);
+    }
+
+    spans.extend(formatting(&text[last..], config, state, base));
+
+    spans
+}
+
+fn formatting(
+    text: &str,
+    config: &Configuration,
+    state: &State,
+    base: Style,
+) -> Vec<Span<'static>> {
+    let re = Regex::new(r"(?s)```(.+?)```|`([^`\n]+)`").unwrap();
+    let code = base
+        .fg(theme::color(config, "code_fg"))
+        .bg(theme::color(config, "code_bg"));
+
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut last = 0;
+
+    for captures in re.captures_iter(text) {
+        let whole = captures.get(0).unwrap();
+        spans.extend(emphasis(&text[last..whole.start()], state, base));
+        last = whole.end();
+
+        let value = captures.get(1).or(captures.get(2)).unwrap();
+        spans.push(Span::styled(unescape(value.as_str()), code));
+    }
+
+    spans.extend(emphasis(&text[last..], state, base));
+
+    spans
+}
+
+fn emphasis(text: &str, state: &State, base: Style) -> Vec<Span<'static>> {
+    let re = Regex::new(r"\*([^*\n]+)\*|_([^_\n]+)_|~([^~\n]+)~").unwrap();
+    let text = emoji::replace(&unescape(text), &state.global.emojis);
+
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut last = 0;
+
+    for captures in re.captures_iter(&text) {
+        let whole = captures.get(0).unwrap();
+        spans.push(Span::styled(text[last..whole.start()].to_string(), base));
+        last = whole.end();
+
+        let (value, style) = if let Some(value) = captures.get(1) {
+            (value, base.add_modifier(Modifier::BOLD))
+        } else if let Some(value) = captures.get(2) {
+            (value, base.add_modifier(Modifier::ITALIC))
+        } else {
+            (
+                captures.get(3).unwrap(),
+                base.add_modifier(Modifier::CROSSED_OUT),
+            )
+        };
+
+        spans.push(Span::styled(value.as_str().to_string(), style));
+    }
+
+    spans.push(Span::styled(text[last..].to_string(), base));
+
+    spans
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
@@ -0,0 +1,31 @@
+use std::str::FromStr;
+
+use ratatui::style::Color;
+
+use crate::{entities::configuration::Configuration, enums::user_mode::UserMode};
+
+pub fn color(config: &Configuration, key: &str) -> Color {
+    config
+        .theme
+        .colors
+        .get(key)
+        .and_then(|value| Color::from_str(value).ok())
+        .unwrap_or(Color::Reset)
+}
+
+pub fn resolve(config: &Configuration, value: &str) -> Color {
+    if config.theme.colors.contains_key(value) {
+        return color(config, value);
+    }
+
+    Color::from_str(value).unwrap_or(Color::Reset)
+}
+
+pub fn mode(config: &Configuration, mode: &UserMode) -> Color {
+    match mode {
+        UserMode::Normal => color(config, "mode_normal"),
+        UserMode::Interact => color(config, "mode_interact"),
+        UserMode::Search => color(config, "mode_search"),
+        UserMode::Command => color(config, "mode_command"),
+    }
+}
@@ -0,0 +1,538 @@
+use std::cmp::min;
+
+use crossterm::event::{self, KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Position, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, Paragraph},
+    Frame,
+};
+
+use crate::{
+    cache::Cache,
+    common::{constants::emoji::EMOJI, enums::request::Request},
+    context::Context,
+    entities::configuration::Configuration,
+    enums::{user_mode::UserMode, widgets::Widgets},
+    states::{Completion, CompletionItem},
+    utils::{
+        self, fuzzy,
+        sequence::Binding,
+        wrap::{self, wrap_spans},
+    },
+};
+
+use super::{common, Widget};
+
+const COMPLETION_LIMIT: usize = 8;
+
+pub fn get<'widget>() -> Widget<'widget> {
+    Widget {
+        commands,
+        keymaps,
+        sequences,
+        build,
+    }
+}
+
+fn commands(_config: &Configuration, command: &str, context: &mut Context) -> Option<Request> {
+    let editor = &mut context.state.input.editor;
+
+    match command {
+        "mode interact" => {
+            context.mode = UserMode::Interact;
+        }
+        "mode append" => {
+            editor.move_right();
+            context.mode = UserMode::Interact;
+        }
+        "mode append line" => {
+            editor.move_line_end();
+            context.mode = UserMode::Interact;
+        }
+        "mode insert line" => {
+            editor.move_line_first_non_blank();
+            context.mode = UserMode::Interact;
+        }
+        "mode normal" => {
+            context.mode = UserMode::Normal;
+            context.state.input.completion = None;
+            return None;
+        }
+        "open below" => {
+            editor.open_line_below();
+            context.mode = UserMode::Interact;
+        }
+        "open above" => {
+            editor.open_line_above();
+            context.mode = UserMode::Interact;
+        }
+        "change line end" => {
+            editor.kill_line_end();
+            context.mode = UserMode::Interact;
+        }
+        "newline" => editor.insert("\n"),
+        "delete backward" => editor.delete_backward(),
+        "delete forward" => editor.delete_forward(),
+        "cursor left" => editor.move_left(),
+        "cursor right" => editor.move_right(),
+        "cursor up" => editor.move_up(),
+        "cursor down" => editor.move_down(),
+        "cursor word backward" => editor.move_word_backward(),
+        "cursor word forward" => editor.move_word_forward(),
+        "cursor word end" => editor.move_word_end(),
+        "cursor line start" => editor.move_line_start(),
+        "cursor line first" => editor.move_line_first_non_blank(),
+        "cursor line end" => editor.move_line_end(),
+        "cursor start" => editor.move_start(),
+        "cursor end" => editor.move_end(),
+        "kill line" => editor.kill_line(),
+        "kill line end" => editor.kill_line_end(),
+        "kill line start" => editor.kill_line_start(),
+        "kill word backward" => editor.kill_word_backward(),
+        "kill word forward" => editor.kill_word_forward(),
+        "kill char forward" => editor.kill_char_forward(),
+        "kill char backward" => editor.kill_char_backward(),
+        "yank" => editor.yank(),
+        "yank after" => editor.yank_after(),
+        "undo" => editor.undo(),
+        "redo" => editor.redo(),
+        command if command.starts_with("insert ") => {
+            editor.insert(&command.replacen("insert ", "", 1));
+        }
+        "completion up" => {
+            if let Some(completion) = context.state.input.completion.as_mut() {
+                completion.selected_index = if completion.selected_index == 0 {
+                    completion.items.len() - 1
+                } else {
+                    completion.selected_index - 1
+                };
+            }
+            return None;
+        }
+        "completion down" => {
+            if let Some(completion) = context.state.input.completion.as_mut() {
+                completion.selected_index =
+                    (completion.selected_index + 1) % completion.items.len();
+            }
+            return None;
+        }
+        "completion accept" => {
+            if let Some(completion) = context.state.input.completion.take() {
+                let item = completion.items[completion.selected_index].clone();
+                let token_length = token(editor.before_cursor()).len();
+
+                editor.replace_before_cursor(token_length, &format!("{} ", item.value));
+            }
+            return None;
+        }
+        "send" => {
+            let text = context.state.input.editor.value().trim_end().to_string();
+            if text.is_empty() {
+                return None;
+            }
+
+            let request = match (
+                context.state.channel.opened.clone(),
+                context.state.message.opened.clone(),
+            ) {
+                (Some(channel), Some(parent)) => {
+                    Request::SendReply(channel.id, parent.ts, text, context.state.thread.broadcast)
+                }
+                (Some(channel), None) => Request::SendMessage(channel.id, text),
+                _ => return None,
+            };
+
+            context.state.input.completion = None;
+            return Some(request);
+        }
+        "open editor" => {
+            context.state.input.completion = None;
+            return Some(Request::OpenEditor(editor.value().to_string()));
+        }
+        "completion close" => {
+            context.state.input.completion = None;
+            return None;
+        }
+        _ => return None,
+    }
+
+    context.state.input.save_draft();
+
+    if context.mode == UserMode::Interact {
+        complete(context);
+    } else {
+        context.state.input.completion = None;
+    }
+
+    None
+}
+
+pub fn keymaps(
+    _config: &Configuration,
+    event: &event::Event,
+    _context: &mut Context,
+) -> Option<String> {
+    if let event::Event::Paste(text) = event {
+        if _context.mode == UserMode::Interact {
+            return Some(format!(
+                "insert {}",
+                text.replace("\r\n", "\n").replace('\r', "\n")
+            ));
+        }
+    }
+
+    let editor = utils::keycode::from_string(_config.keymaps.editor.clone());
+    let send = utils::keycode::from_string(_config.keymaps.send.clone());
+
+    if let event::Event::Key(KeyEvent {
+        modifiers, code, ..
+    }) = event.clone()
+    {
+        if (modifiers, code) == editor {
+            return Some(String::from("open editor"));
+        }
+        if (modifiers, code) == send {
+            return Some(String::from("send"));
+        }
+
+        if _context.mode != UserMode::Interact {
+            return normal_keymaps(_config, modifiers, code).map(String::from);
+        }
+
+        let typed = match code {
+            KeyCode::Char(char)
+                if !modifiers.contains(KeyModifiers::CONTROL)
+                    || modifiers.contains(KeyModifiers::ALT) =>
+            {
+                Some(format!("insert {}", char))
+            }
+            _ => None,
+        };
+
+        return interact_keymaps(_config, _context, modifiers, code)
+            .map(String::from)
+            .or(typed);
+    }
+
+    None
+}
+
+pub fn sequences(_config: &Configuration, _context: &Context) -> Vec<Binding> {
+    if _context.mode == UserMode::Interact {
+        return Vec::new();
+    }
+
+    vec![Binding::new(_config.keymaps.top.clone(), "cursor start")]
+}
+
+fn normal_keymaps(
+    config: &Configuration,
+    modifiers: KeyModifiers,
+    code: KeyCode,
+) -> Option<&'static str> {
+    let interact = utils::keycode::from_string(config.keymaps.interact.clone());
+    let up = utils::keycode::from_string(config.keymaps.up.clone());
+    let down = utils::keycode::from_string(config.keymaps.down.clone());
+    let left = utils::keycode::from_string(config.keymaps.left.clone());
+    let right = utils::keycode::from_string(config.keymaps.right.clone());
+
+    match (modifiers, code) {
+        key if key == interact => Some("mode interact"),
+        key if key == up => Some("cursor up"),
+        key if key == down => Some("cursor down"),
+        key if key == left => Some("cursor left"),
+        key if key == right => Some("cursor right"),
+        _ => None,
+    }
+}
+
+fn interact_keymaps(
+    config: &Configuration,
+    context: &Context,
+    modifiers: KeyModifiers,
+    code: KeyCode,
+) -> Option<&'static str> {
+    let up = navigation(utils::keycode::from_string(config.keymaps.up.clone()));
+    let down = navigation(utils::keycode::from_string(config.keymaps.down.clone()));
+
+    let completing = context.state.input.completion.is_some();
+
+    match (modifiers, code) {
+        (_, KeyCode::Esc) if completing => Some("completion close"),
+        (_, KeyCode::Esc) => Some("mode normal"),
+        (_, KeyCode::Up) if completing => Some("completion up"),
+        (_, KeyCode::Down) if completing => Some("completion down"),
+        key if completing && key == up => Some("completion up"),
+        key if completing && key == down => Some("completion down"),
+        (_, KeyCode::Tab) | (_, KeyCode::Enter) if completing => Some("completion accept"),
+        (_, KeyCode::Enter) => Some("newline"),
+        (KeyModifiers::ALT, KeyCode::Backspace) => Some("kill word backward"),
+        (_, KeyCode::Backspace) => Some("delete backward"),
+        (_, KeyCode::Delete) => Some("delete forward"),
+        (KeyModifiers::CONTROL, KeyCode::Left) => Some("cursor word backward"),
+        (KeyModifiers::CONTROL, KeyCode::Right) => Some("cursor word forward"),
+        (_, KeyCode::Left) => Some("cursor left"),
+        (_, KeyCode::Right) => Some("cursor right"),
+        (_, KeyCode::Up) => Some("cursor up"),
+        (_, KeyCode::Down) => Some("cursor down"),
+        (_, KeyCode::Home) => Some("cursor line start"),
+        (_, KeyCode::End) => Some("cursor line end"),
+        (KeyModifiers::CONTROL, KeyCode::Char(char)) => match char {
+            'a' => Some("cursor line start"),
+            'e' => Some("cursor line end"),
+            'b' => Some("cursor left"),
+            'f' => Some("cursor right"),
+            'd' => Some("delete forward"),
+            'k' => Some("kill line end"),
+            'u' => Some("kill line start"),
+            'w' => Some("kill word backward"),
+            'y' => Some("yank"),
+            'z' => Some("undo"),
+            'r' => Some("redo"),
+            _ => None,
+        },
+        (KeyModifiers::ALT, KeyCode::Char('b')) => Some("cursor word backward"),
+        (KeyModifiers::ALT, KeyCode::Char('f')) => Some("cursor word forward"),
+        (KeyModifiers::ALT, KeyCode::Char('d')) => Some("kill word forward"),
+        _ => None,
+    }
+}
+
+pub fn height(config: &Configuration, context: &Context, width: u16, max_height: u16) -> u16 {
+    let lines = lines(config, context, width.saturating_sub(2) as usize).len();
+    min(lines as u16, max_height) + 2
+}
+
+pub fn build(
+    _config: &Configuration,
+    frame: &mut Frame,
+    _context: &Context,
+    _cache: &mut Cache,
+    rect: Rect,
+) {
+    let is_focus = _context.is_focus(&String::from("input"));
+    let width = rect.width.saturating_sub(2) as usize;
+    let height = rect.height.saturating_sub(2) as usize;
+
+    let editor = &_context.state.input.editor;
+    let lines = lines(_config, _context, width);
+    let (row, column) = if editor.is_empty() {
+        (0, 0)
+    } else {
+        wrap::locate(&lines, editor.value(), editor.before_cursor().len())
+    };
+
+    let skip = (row + 1).saturating_sub(height);
+    let visible: Vec<Line> = lines.into_iter().skip(skip).take(height).collect();
+
+    if let Widgets::Block(mut block) = common::block::build(_config, is_focus, &_context.mode) {
+        if _context.state.message.opened.is_some() {
+            let broadcast = if _context.state.thread.broadcast {
+                "x"
+            } else {
+                " "
+            };
+            block = block.title(format!("Reply [{}] Also send to channel", broadcast));
+        }
+
+        frame.render_widget(Paragraph::new(visible).block(block), rect);
+
+        if is_focus {
+            frame.set_cursor_position(Position::new(
+                rect.x + 1 + column as u16,
+                rect.y + 1 + (row - skip) as u16,
+            ));
+        }
+
+        if is_focus && _context.mode == UserMode::Interact {
+            completion(_config, frame, _context, rect);
+        }
+    }
+}
+
+fn lines(config: &Configuration, context: &Context, width: usize) -> Vec<Line<'static>> {
+    if context.state.input.editor.is_empty() {
+        let placeholder = match context.state.channel.opened.clone() {
+            Some(_) if context.state.message.opened.is_some() => String::from("Reply in thread"),
+            Some(channel) => format!(
+                "Message {} {}",
+                common::channel::icon(&channel),
+                common::channel::title(&channel, context)
+            ),
+            None => String::from("Select channel and start messaging"),
+        };
+
+        return vec![Line::styled(
+            placeholder,
+            Style::default().fg(common::theme::color(config, "dimmed")),
+        )];
+    }
+
+    wrap(context.state.input.editor.value(), width)
+}
+
+fn wrap(text: &str, width: usize) -> Vec<Line<'static>> {
+    wrap_spans(vec![Span::raw(text.to_string())], width)
+        .into_iter()
+        .map(Line::from)
+        .collect()
+}
+
+fn completion(config: &Configuration, frame: &mut Frame, context: &Context, rect: Rect) {
+    let Some(completion) = &context.state.input.completion else {
+        return;
+    };
+
+    let highlight = Style::default()
+        .fg(common::theme::color(config, "match"))
+        .add_modifier(Modifier::BOLD);
+    let detail = Style::default().fg(common::theme::color(config, "dimmed"));
+
+    let items: Vec<ListItem> = completion
+        .items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let mut spans = vec![Span::raw(
+                item.value.chars().next().unwrap_or_default().to_string(),
+            )];
+
+            for (position, char) in item.label.chars().enumerate() {
+                if item.indices.contains(&position) {
+                    spans.push(Span::styled(char.to_string(), highlight));
+                } else {
+                    spans.push(Span::raw(char.to_string()));
+                }
+            }
+
+            if !item.detail.is_empty() {
+                spans.push(Span::styled(format!("  {}", item.detail), detail));
+            }
+
+            let style = if index == completion.selected_index {
+                Style::default()
+                    .bg(common::theme::color(config, "selection_bg"))
+                    .fg(common::theme::color(config, "selection_fg"))
+            } else {
+                Style::default()
+            };
+
+            ListItem::new(Line::from(spans)).style(style)
+        })
+        .collect();
+
+    let height = min(items.len() as u16 + 2, rect.y);
+    let popup = Rect::new(rect.x, rect.y - height, min(rect.width, 50), height);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(common::theme::color(config, "border")));
+
+    frame.render_widget(Clear, popup);
+    frame.render_widget(List::new(items).block(block), popup);
+}
+
+fn navigation(key: (KeyModifiers, KeyCode)) -> (KeyModifiers, KeyCode) {
+    match key {
+        (KeyModifiers::NONE, KeyCode::Char(char)) => (KeyModifiers::CONTROL, KeyCode::Char(char)),
+        key => key,
+    }
+}
+
+fn token(value: &str) -> &str {
+    value.rsplit(char::is_whitespace).next().unwrap_or_default()
+}
+
+fn complete(context: &mut Context) {
+    let state = &context.state;
+    let token = token(state.input.editor.before_cursor());
+
+    let mut chars = token.chars();
+    let trigger = chars.next();
+    let query = chars.as_str();
+
+    let mut candidates: Vec<(String, String, String)> = Vec::new();
+
+    match trigger {
+        Some('@') => {
+            for (special, detail) in [
+                ("here", "Notify active members"),
+                ("channel", "Notify everyone in this channel"),
+                ("everyone", "Notify everyone in the workspace"),
+            ] {
+                candidates.push((
+                    special.to_string(),
+                    detail.to_string(),
+                    format!("@{}", special),
+                ));
+            }
+            for usergroup in state.global.usergroups.iter() {
+                candidates.push((
+                    usergroup.handle.clone(),
+                    usergroup.name.clone(),
+                    format!("@{}", usergroup.handle),
+                ));
+            }
+            for member in state.global.members.iter().filter(|member| !member.deleted) {
+                let name = if member.profile.display_name.is_empty() {
+                    member.name.clone()
+                } else {
+                    member.profile.display_name.clone()
+                };
+                candidates.push((
+                    name.clone(),
+                    member.real_name.clone().unwrap_or_default(),
+                    format!("@{}", name),
+                ));
+            }
+        }
+        Some('#') => {
+            for channel in state.channel.channels.iter() {
+                if let Some(name) = channel.name.clone() {
+                    candidates.push((name.clone(), String::new(), format!("#{}", name)));
+                }
+            }
+        }
+        Some(':') if query.chars().count() >= 2 => {
+            for (name, unicode) in EMOJI.iter() {
+                candidates.push((name.to_string(), unicode.to_string(), format!(":{}:", name)));
+            }
+            for name in state.global.emojis.keys() {
+                candidates.push((name.clone(), String::from("custom"), format!(":{}:", name)));
+            }
+        }
+        _ => {}
+    }
+
+    let mut items: Vec<(i64, CompletionItem)> = candidates
+        .into_iter()
+        .filter_map(|(label, detail, value)| {
+            fuzzy::find(query, &label).map(|(score, indices)| {
+                (
+                    score,
+                    CompletionItem {
+                        label,
+                        detail,
+                        value,
+                        indices,
+                    },
+                )
+            })
+        })
+        .collect();
+
+    items.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.label.cmp(&b.1.label)));
+    items.truncate(COMPLETION_LIMIT);
+
+    context.state.input.completion = if items.is_empty() {
+        None
+    } else {
+        Some(Completion {
+            items: items.into_iter().map(|(_, item)| item).collect(),
+            selected_index: 0,
+        })
+    };
+}
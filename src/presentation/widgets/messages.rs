@@ -1,2 +1,192 @@
+use std::cmp::min;
 
+use crossterm::event::{self, KeyEvent};
+use ratatui::{layout::Rect, style::Style, text::Line, widgets::Paragraph, Frame};
 
+use crate::{
+    cache::Cache,
+    common::enums::request::Request,
+    context::Context,
+    entities::{configuration::Configuration, slack::messages::Message},
+    enums::widgets::Widgets,
+    utils::{self, datetime, sequence::Binding},
+};
+
+use super::{
+    common::{self, message},
+    thread, Widget,
+};
+
+pub fn get<'widget>() -> Widget<'widget> {
+    Widget {
+        commands,
+        keymaps,
+        sequences,
+        build,
+    }
+}
+
+fn commands(_config: &Configuration, command: &str, context: &mut Context) -> Option<Request> {
+    let state = &context.state.message;
+    let len = state.messages.len();
+    if let Some(index) = common::list::moved(command, state.selected_index, len) {
+        select(context, index);
+        return None;
+    }
+
+    match command {
+        "open links" => {
+            if let Some(message) = context.state.message.selected.clone() {
+                context.state.global.error = common::block_kit::open_links(&message).err();
+            }
+        }
+        "open thread" => {
+            return thread::open(context);
+        }
+        "edit message" => {
+            return edit(context, context.state.message.selected.clone());
+        }
+        _ => {}
+    }
+    None
+}
+
+pub fn edit(context: &mut Context, message: Option<Message>) -> Option<Request> {
+    let (channel, message) = (context.state.channel.opened.clone()?, message?);
+    let user_id = context.auth.as_ref().map(|auth| &auth.authed_user.id);
+
+    if message.user.as_ref() != user_id {
+        context.state.global.error = Some(String::from("Only your own messages can be edited"));
+        return None;
+    }
+
+    let text = utils::mention::decode(&message.text.unwrap_or_default(), &context.state);
+
+    Some(Request::EditMessage(channel.id, message.ts, text))
+}
+
+fn select(context: &mut Context, index: Option<usize>) {
+    let message = &mut context.state.message;
+    message.selected_index = index;
+    message.selected = index.and_then(|index| message.messages.get(index).cloned());
+}
+
+pub fn sequences(_config: &Configuration, _context: &Context) -> Vec<Binding> {
+    vec![
+        Binding::new(_config.keymaps.top.clone(), "move top"),
+        Binding::new(_config.keymaps.bottom.clone(), "move bottom"),
+        Binding::new(_config.keymaps.links.clone(), "open links"),
+    ]
+}
+
+pub fn keymaps(
+    _config: &Configuration,
+    event: &event::Event,
+    _context: &mut Context,
+) -> Option<String> {
+    let up = utils::keycode::from_string(_config.keymaps.up.clone());
+    let down = utils::keycode::from_string(_config.keymaps.down.clone());
+    let open = utils::keycode::from_string(_config.keymaps.open.clone());
+    let editor = utils::keycode::from_string(_config.keymaps.editor.clone());
+
+    if let event::Event::Key(KeyEvent {
+        modifiers, code, ..
+    }) = event.clone()
+    {
+        match (modifiers, code) {
+            key if key == up => {
+                return Some(String::from("move up 1"));
+            }
+            key if key == down => {
+                return Some(String::from("move down 1"));
+            }
+            key if key == open && _context.state.message.selected.is_some() => {
+                return Some(String::from("open thread"));
+            }
+            key if key == editor && _context.state.message.selected.is_some() => {
+                return Some(String::from("edit message"));
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+pub fn build(
+    _config: &Configuration,
+    frame: &mut Frame,
+    _context: &Context,
+    _cache: &mut Cache,
+    rect: Rect,
+) {
+    let is_focus = _context.is_focus(&String::from("messages"));
+    let width = rect.width.saturating_sub(2) as usize;
+    let height = rect.height.saturating_sub(2) as usize;
+
+    let message_state = &_context.state.message;
+    let selected_style = Style::default().bg(common::theme::color(_config, "highlight_bg"));
+
+    let mut lines: Vec<Line> = Vec::new();
+    let mut selected_range: Option<(usize, usize)> = None;
+    let mut prev_date = String::new();
+
+    for (index, message) in message_state.messages.iter().enumerate() {
+        let date = datetime::parse(_config, &message.ts)
+            .map_or(String::new(), |datetime| datetime::date(_config, &datetime));
+
+        if prev_date != date {
+            prev_date = date.clone();
+            lines.push(message::separator(_config, &date, width));
+        }
+
+        let selected = message_state.selected_index == Some(index);
+        let message_lines =
+            message::build(message, _config, &_context.state, _cache, width, selected);
+
+        let start = lines.len();
+
+        if selected {
+            selected_range = Some((start, start + message_lines.len()));
+            lines.extend(
+                message_lines
+                    .into_iter()
+                    .map(|line| line.patch_style(selected_style)),
+            );
+        } else {
+            lines.extend(message_lines);
+        }
+    }
+
+    let skip = match selected_range {
+        Some((start, end)) => min(start, end.saturating_sub(height)),
+        None => lines.len().saturating_sub(height),
+    };
+
+    let visible: Vec<Line> = if lines.is_empty() {
+        let placeholder = if _context.state.channel.opened.is_some() {
+            "No messages yet"
+        } else {
+            "Select a channel to read messages"
+        };
+        vec![Line::styled(
+            placeholder,
+            Style::default().fg(common::theme::color(_config, "dimmed")),
+        )]
+    } else {
+        lines.into_iter().skip(skip).take(height).collect()
+    };
+
+    let title = _context
+        .state
+        .channel
+        .opened
+        .clone()
+        .map_or(String::from("Messages"), |channel| {
+            common::channel::title(&channel, _context)
+        });
+
+    if let Widgets::Block(block) = common::block::build(_config, is_focus, &_context.mode) {
+        frame.render_widget(Paragraph::new(visible).block(block.title(title)), rect);
+    }
+}
@@ -3,20 +3,27 @@ use ratatui::{layout::Rect, Frame};
 
 use crate::{
     cache::Cache, common::enums::request::Request, context::Context,
-    entities::configuration::Configuration,
+    entities::configuration::Configuration, utils::sequence::Binding,
 };
 
 pub mod channels;
+pub mod command_line;
+pub mod common;
+pub mod input;
 pub mod loading;
 pub mod messages;
 pub mod status_line;
+pub mod switcher;
+pub mod thread;
+pub mod which_key;
 
 #[derive(Clone)]
 pub struct Widget<'widget> {
     pub commands:
-        fn(config: &Configuration, command: &String, context: &mut Context) -> Option<Request>,
+        fn(config: &Configuration, command: &str, context: &mut Context) -> Option<Request>,
     pub keymaps:
         fn(config: &Configuration, event: &event::Event, context: &mut Context) -> Option<String>,
+    pub sequences: fn(config: &Configuration, context: &Context) -> Vec<Binding>,
     pub build: fn(
         config: &Configuration,
         frame: &mut Frame,
@@ -1,124 +1,251 @@
-use std::collections::HashMap;
+use std::{cmp::min, collections::HashMap};
 
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
     Frame,
 };
+use regex::{Captures, Regex};
 
-use crate::{context::Context, entities::configuration::Configuration};
+use crate::{
+    context::Context,
+    entities::configuration::{Configuration, StatusLineSide},
+    utils::{datetime, wrap::truncate_spans},
+};
+
+use super::common;
 
 pub fn render(frame: &mut Frame, rect: Rect, _config: &Configuration, _context: &Context) {
-    let authorization = _context.auth.clone().unwrap();
-    let global = _context.state.global.clone();
+    let placeholders = placeholders(_config, _context);
+
+    let left_spans = spans(
+        _config,
+        _context,
+        &_config.status_line.left,
+        &placeholders,
+        false,
+    );
+    let right_spans = spans(
+        _config,
+        _context,
+        &_config.status_line.right,
+        &placeholders,
+        true,
+    );
+
+    let width = rect.width as usize;
+    let left_width: usize = left_spans.iter().map(|span| span.width()).sum();
+    let right_width: usize = right_spans.iter().map(|span| span.width()).sum();
+    let right_budget = min(
+        right_width,
+        width.saturating_sub(min(left_width, width / 2)),
+    );
+    let left_budget = width - right_budget;
+
+    let rects = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Length(left_budget as u16),
+            Constraint::Length(right_budget as u16),
+        ])
+        .split(rect);
+
+    let left_line =
+        Line::from(truncate_spans(left_spans, left_budget, false)).alignment(Alignment::Left);
+    let right_line =
+        Line::from(truncate_spans(right_spans, right_budget, true)).alignment(Alignment::Right);
+
+    frame.render_widget(left_line, rects[0]);
+    frame.render_widget(right_line, rects[1]);
+}
+
+fn placeholders(config: &Configuration, context: &Context) -> HashMap<&'static str, String> {
+    let authorization = context.auth.clone().unwrap();
+    let global = &context.state.global;
     let user = global
         .get_user(authorization.authed_user.id)
         .expect("User is logged in");
-    let channel_name = _context
+
+    let channel_state = &context.state.channel;
+    let conversations = channel_state
+        .channels
+        .iter()
+        .chain(channel_state.direct_messages.iter());
+
+    let muted = common::channel::muted(config);
+    let mut unread = 0;
+    let mut mentions = 0;
+    for channel in conversations {
+        let title = common::channel::title(channel, context);
+        unread += common::channel::unread(channel, &title, &muted);
+        mentions += common::channel::mentions(channel, &title, &muted, channel_state);
+    }
+
+    let thread = context
         .state
-        .channel
+        .message
         .opened
-        .clone()
-        .map_or(String::new(), |channel| channel.name.unwrap_or(channel.id));
-    let mode = _context.mode.clone();
-    let mode_name = mode.to_string();
+        .as_ref()
+        .map_or(String::new(), |message| {
+            let replies = message
+                .reply_count
+                .unwrap_or_default()
+                .max(context.state.thread.messages.len().saturating_sub(1) as u32);
+            format!(
+                "{} {}",
+                replies,
+                if replies == 1 { "reply" } else { "replies" }
+            )
+        });
 
-    let mut placeholders: HashMap<&str, &str> = HashMap::new();
-    placeholders.insert("user", user.profile.display_name.as_str());
-    placeholders.insert("team", authorization.team.name.as_str());
-    placeholders.insert("mode", mode_name.as_str());
-    placeholders.insert("channel", channel_name.as_str());
+    let last_sync = global
+        .last_sync
+        .and_then(|timestamp| datetime::from_timestamp(config, timestamp))
+        .map_or(String::new(), |datetime| datetime::time(config, &datetime));
 
-    let rects = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(rect);
-    let left_rect = rects[0];
-    let right_rect = rects[1];
+    let mut placeholders: HashMap<&str, String> = HashMap::new();
+    placeholders.insert("user", user.profile.display_name);
+    placeholders.insert("team", authorization.team.name);
+    placeholders.insert("mode", context.mode.to_string());
+    placeholders.insert(
+        "channel",
+        channel_state
+            .opened
+            .clone()
+            .map_or(String::new(), |channel| {
+                common::channel::title(&channel, context)
+            }),
+    );
+    placeholders.insert("unread", unread.to_string());
+    placeholders.insert("mentions", mentions.to_string());
+    placeholders.insert("time", datetime::time(config, &datetime::now(config)));
+    placeholders.insert("presence", global.presence.clone().unwrap_or_default());
+    placeholders.insert("connection", global.connection.to_string());
+    placeholders.insert("last_sync", last_sync);
+    placeholders.insert("thread", thread);
+
+    placeholders
+}
+
+fn spans(
+    config: &Configuration,
+    context: &Context,
+    side: &StatusLineSide,
+    placeholders: &HashMap<&str, String>,
+    reverse: bool,
+) -> Vec<Span<'static>> {
+    let text = substitute(&side.template, placeholders);
+
+    let segments: Vec<String> = text.split("<>").map(|text| text.to_owned()).collect();
+    let styles: Vec<Style> = (0..segments.len())
+        .map(|index| style(config, context, side, index))
+        .collect();
+    let background = style(config, context, side, usize::MAX)
+        .bg
+        .unwrap_or(Color::Reset);
 
-    let mut left_text = _config.status_line.left.template.clone();
-    let mut right_text = _config.status_line.right.template.clone();
+    let mut spans: Vec<Span<'static>> = Vec::new();
 
-    for (key, value) in placeholders {
-        left_text = left_text.replace(&format!("%{}%", key), value);
-        right_text = right_text.replace(&format!("%{}%", key), value);
+    for (index, segment) in segments.into_iter().enumerate() {
+        let bg = styles[index].bg.unwrap_or(Color::Reset);
+        let next_bg = styles
+            .get(index + 1)
+            .map_or(background, |style| style.bg.unwrap_or(Color::Reset));
+
+        let mut part = vec![Span::styled(segment, styles[index])];
+
+        if bg != next_bg {
+            part.push(Span::styled(
+                side.separator.clone(),
+                Style::default().fg(bg).bg(next_bg),
+            ));
+        }
+
+        if reverse {
+            part.reverse();
+            spans.splice(0..0, part);
+        } else {
+            spans.extend(part);
+        }
+    }
+
+    spans
+}
+
+// One pass, so values that look like placeholders are never expanded again
+fn substitute(template: &str, placeholders: &HashMap<&str, String>) -> String {
+    let re = Regex::new(r"%(\w+)%").unwrap();
+
+    re.replace_all(template, |captures: &Captures| {
+        placeholders
+            .get(&captures[1])
+            .cloned()
+            .unwrap_or(captures[0].to_string())
+    })
+    .into_owned()
+}
+
+fn style(config: &Configuration, context: &Context, side: &StatusLineSide, index: usize) -> Style {
+    let style = match index {
+        0 => Style::default()
+            .fg(common::theme::color(config, "status_primary_fg"))
+            .bg(common::theme::mode(config, &context.mode)),
+        1 => Style::default()
+            .fg(common::theme::color(config, "status_secondary_fg"))
+            .bg(common::theme::color(config, "status_secondary_bg")),
+        _ => Style::default()
+            .fg(common::theme::color(config, "status_tertiary_fg"))
+            .bg(common::theme::color(config, "status_tertiary_bg")),
+    };
+
+    let Some(custom) = side.styles.get(index) else {
+        return style;
+    };
+
+    let mut style = style;
+
+    if let Some(fg) = &custom.fg {
+        style = style.fg(common::theme::resolve(config, fg));
+    }
+    if let Some(bg) = &custom.bg {
+        style = style.bg(common::theme::resolve(config, bg));
     }
+    if custom.bold.unwrap_or(false) {
+        style = style.add_modifier(Modifier::BOLD);
+    }
+
+    style
+}
 
-    let splited_left = left_text
-        .split("<>")
-        .map(|text| text.to_owned())
-        .collect::<Vec<String>>();
-    let splited_right = right_text
-        .split("<>")
-        .map(|text| text.to_owned())
-        .collect::<Vec<String>>();
-
-    let primary_left = splited_left.get(0).unwrap_or(&String::new()).clone();
-    let secondary_left = splited_left.get(1).unwrap_or(&String::new()).clone();
-    let teriary_left = splited_left.get(2).unwrap_or(&String::new()).clone();
-
-    let left_spans: Vec<Span> = vec![
-        Span::styled(
-            primary_left,
-            Style::default()
-                .fg(Color::Black)
-                .bg(_context.mode.to_color()),
-        ),
-        Span::styled(
-            _config.status_line.left.separator.clone(),
-            Style::default()
-                .fg(_context.mode.to_color())
-                .bg(Color::DarkGray),
-        ),
-        Span::styled(
-            secondary_left,
-            Style::default().fg(Color::White).bg(Color::DarkGray),
-        ),
-        Span::styled(
-            _config.status_line.left.separator.clone(),
-            Style::default().fg(Color::DarkGray).bg(Color::default()),
-        ),
-        Span::styled(
-            teriary_left,
-            Style::default().fg(Color::White).bg(Color::default()),
-        ),
-    ];
-
-    let primary_right = splited_right.get(0).unwrap_or(&String::new()).clone();
-    let secondary_right = splited_right.get(1).unwrap_or(&String::new()).clone();
-    let teriary_right = splited_right.get(2).unwrap_or(&String::new()).clone();
-
-    let right_spans: Vec<Span> = vec![
-        Span::styled(
-            teriary_right,
-            Style::default().fg(Color::White).bg(Color::default()),
-        ),
-        Span::styled(
-            _config.status_line.right.separator.clone(),
-            Style::default().fg(Color::DarkGray).bg(Color::default()),
-        ),
-        Span::styled(
-            secondary_right,
-            Style::default().fg(Color::White).bg(Color::DarkGray),
-        ),
-        Span::styled(
-            _config.status_line.right.separator.clone(),
-            Style::default()
-                .fg(_context.mode.to_color())
-                .bg(Color::DarkGray),
-        ),
-        Span::styled(
-            primary_right,
-            Style::default()
-                .fg(Color::Black)
-                .bg(_context.mode.to_color()),
-        ),
-    ];
-
-    let left_line = Line::from(left_spans).alignment(Alignment::Left);
-    let right_line = Line::from(right_spans).alignment(Alignment::Right);
-
-    frame.render_widget(left_line, left_rect);
-    frame.render_widget(right_line, right_rect);
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::substitute;
+
+    #[test]
+    fn substitute_expands_each_placeholder_once() {
+        let placeholders = HashMap::from([
+            ("channel", String::from("%time%")),
+            ("time", String::from("12:00")),
+            ("user", String::from("100%")),
+        ]);
+
+        let cases = [
+            ("%channel% at %time%", "%time% at 12:00"),
+            ("%user%%time%", "100%12:00"),
+            ("%missing% %user%", "%missing% 100%"),
+            ("50% off", "50% off"),
+        ];
+
+        for (template, expected) in cases {
+            assert_eq!(
+                substitute(template, &placeholders),
+                expected,
+                "{}",
+                template
+            );
+        }
+    }
 }
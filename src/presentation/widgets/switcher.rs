@@ -0,0 +1,330 @@
+use std::cmp::{min, Reverse};
+
+use crossterm::event::{self, KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Position, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Clear, List, ListItem},
+    Frame,
+};
+
+use crate::{
+    cache::Cache,
+    common::{constants::icon, enums::request::Request},
+    context::Context,
+    entities::configuration::Configuration,
+    enums::{user_mode::UserMode, widgets::Widgets},
+    states::{CompletionItem, Switcher},
+    utils::{fuzzy, sequence::Binding, wrap},
+};
+
+use super::{channels, common, Widget};
+
+const SWITCHER_LIMIT: usize = 10;
+const SWITCHER_WIDTH: u16 = 60;
+const USER_PREFIX: &str = "user:";
+
+pub fn get<'widget>() -> Widget<'widget> {
+    Widget {
+        commands,
+        keymaps,
+        sequences,
+        build,
+    }
+}
+
+fn commands(_config: &Configuration, command: &str, context: &mut Context) -> Option<Request> {
+    let switcher = context.state.channel.switcher.as_mut()?;
+
+    match command {
+        "move up" => {
+            switcher.selected_index = if switcher.selected_index == 0 {
+                switcher.items.len().saturating_sub(1)
+            } else {
+                switcher.selected_index - 1
+            };
+            return None;
+        }
+        "move down" => {
+            switcher.selected_index = (switcher.selected_index + 1) % switcher.items.len().max(1);
+            return None;
+        }
+        "close" => {
+            close(context);
+            return None;
+        }
+        "accept" => {
+            let item = switcher.items.get(switcher.selected_index).cloned();
+            close(context);
+
+            let value = item?.value;
+            if let Some(user_id) = value.strip_prefix(USER_PREFIX) {
+                return Some(Request::OpenDirectMessage(user_id.to_string()));
+            }
+            return switch(context, &value);
+        }
+        "delete backward" => {
+            switcher.query.pop();
+        }
+        "clear" => {
+            switcher.query.clear();
+        }
+        command if command.starts_with("insert ") => {
+            switcher.query.push_str(&command.replacen("insert ", "", 1));
+        }
+        _ => return None,
+    }
+
+    refresh(_config, context);
+    None
+}
+
+pub fn open(config: &Configuration, context: &mut Context) {
+    context.state.channel.switcher = Some(Switcher {
+        query: String::new(),
+        items: Vec::new(),
+        selected_index: 0,
+    });
+    context.mode = UserMode::Search;
+    refresh(config, context);
+}
+
+pub fn switch(context: &mut Context, channel_id: &str) -> Option<Request> {
+    let request = channels::open(context, channel_id);
+
+    if request.is_some() {
+        context.state.channel.selected = context.state.channel.opened.clone();
+        context.set_focus(String::from("messages"));
+    }
+
+    request
+}
+
+fn close(context: &mut Context) {
+    context.state.channel.switcher = None;
+    context.mode = UserMode::Normal;
+}
+
+fn refresh(config: &Configuration, context: &mut Context) {
+    let items = items(config, context);
+
+    if let Some(switcher) = context.state.channel.switcher.as_mut() {
+        switcher.items = items;
+        switcher.selected_index = 0;
+    }
+}
+
+fn items(config: &Configuration, context: &Context) -> Vec<CompletionItem> {
+    let channel_state = &context.state.channel;
+    let query = channel_state
+        .switcher
+        .as_ref()
+        .map_or(String::new(), |switcher| switcher.query.clone());
+
+    let mut conversations: Vec<_> = channel_state
+        .channels
+        .iter()
+        .chain(channel_state.direct_messages.iter())
+        .collect();
+    conversations.sort_by_key(|channel| Reverse(channel.updated.unwrap_or(0)));
+
+    let total = conversations.len().max(1) as i64;
+    let muted = common::channel::muted(config);
+    let mut candidates: Vec<(String, String, String, i64)> = Vec::new();
+
+    for (rank, channel) in conversations.iter().enumerate() {
+        let title = common::channel::title(channel, context);
+        let mut boost = 10 * (total - rank as i64) / total;
+
+        if let Some(position) = channel_state
+            .recent
+            .iter()
+            .position(|recent| recent == &channel.id)
+        {
+            boost += (30 - 3 * position as i64).max(0);
+        }
+        if common::channel::unread(channel, &title, &muted) > 0 {
+            boost += 20;
+        }
+
+        let detail = if channel.is_im {
+            "direct message"
+        } else if channel.is_mpim.unwrap_or(false) {
+            "group message"
+        } else if channel.is_private.unwrap_or(false) {
+            "private channel"
+        } else {
+            "channel"
+        };
+
+        candidates.push((title, detail.to_string(), channel.id.clone(), boost));
+    }
+
+    for member in context.state.global.members.iter() {
+        let has_direct_message = channel_state
+            .direct_messages
+            .iter()
+            .any(|channel| channel.user.as_ref() == Some(&member.id));
+
+        if member.deleted || has_direct_message {
+            continue;
+        }
+
+        let name = if member.profile.display_name.is_empty() {
+            member.name.clone()
+        } else {
+            member.profile.display_name.clone()
+        };
+
+        candidates.push((
+            name,
+            member.real_name.clone().unwrap_or_default(),
+            format!("{}{}", USER_PREFIX, member.id),
+            0,
+        ));
+    }
+
+    let mut items: Vec<(i64, CompletionItem)> = candidates
+        .into_iter()
+        .filter_map(|(label, detail, value, boost)| {
+            fuzzy::find(&query, &label).map(|(score, indices)| {
+                (
+                    score + boost,
+                    CompletionItem {
+                        label,
+                        detail,
+                        value,
+                        indices,
+                    },
+                )
+            })
+        })
+        .collect();
+
+    items.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.label.cmp(&b.1.label)));
+    items.truncate(SWITCHER_LIMIT);
+
+    items.into_iter().map(|(_, item)| item).collect()
+}
+
+pub fn sequences(_config: &Configuration, _context: &Context) -> Vec<Binding> {
+    Vec::new()
+}
+
+pub fn keymaps(
+    _config: &Configuration,
+    event: &event::Event,
+    _context: &mut Context,
+) -> Option<String> {
+    if let event::Event::Paste(text) = event {
+        return Some(format!("insert {}", text.replace(['\r', '\n'], " ")));
+    }
+
+    if let event::Event::Key(KeyEvent {
+        modifiers, code, ..
+    }) = event.clone()
+    {
+        let command = match (modifiers, code) {
+            (_, KeyCode::Esc) => "close",
+            (_, KeyCode::Enter) => "accept",
+            (_, KeyCode::Up) | (KeyModifiers::CONTROL, KeyCode::Char('p')) => "move up",
+            (_, KeyCode::Down) | (KeyModifiers::CONTROL, KeyCode::Char('n')) => "move down",
+            (_, KeyCode::Backspace) => "delete backward",
+            (KeyModifiers::CONTROL, KeyCode::Char('u')) => "clear",
+            (modifiers, KeyCode::Char(char))
+                if !modifiers.contains(KeyModifiers::CONTROL)
+                    || modifiers.contains(KeyModifiers::ALT) =>
+            {
+                return Some(format!("insert {}", char));
+            }
+            _ => return None,
+        };
+
+        return Some(String::from(command));
+    }
+
+    None
+}
+
+pub fn build(
+    _config: &Configuration,
+    frame: &mut Frame,
+    _context: &Context,
+    _cache: &mut Cache,
+    rect: Rect,
+) {
+    let Some(switcher) = &_context.state.channel.switcher else {
+        return;
+    };
+
+    let highlight = Style::default()
+        .fg(common::theme::color(_config, "match"))
+        .add_modifier(Modifier::BOLD);
+    let detail = Style::default().fg(common::theme::color(_config, "dimmed"));
+
+    let mut items: Vec<ListItem> = vec![ListItem::new(Line::from(vec![
+        Span::styled("❯ ", detail),
+        Span::raw(switcher.query.clone()),
+    ]))];
+
+    for (index, item) in switcher.items.iter().enumerate() {
+        let prefix = if item.value.starts_with(USER_PREFIX) {
+            icon::USER
+        } else {
+            _context
+                .state
+                .channel
+                .get_channel(item.value.clone())
+                .map_or(icon::HASHTAG, |channel| common::channel::icon(&channel))
+        };
+
+        let mut spans = vec![Span::raw(format!("{} ", prefix))];
+
+        for (position, char) in item.label.chars().enumerate() {
+            if item.indices.contains(&position) {
+                spans.push(Span::styled(char.to_string(), highlight));
+            } else {
+                spans.push(Span::raw(char.to_string()));
+            }
+        }
+
+        if !item.detail.is_empty() {
+            spans.push(Span::styled(format!("  {}", item.detail), detail));
+        }
+
+        let style = if index == switcher.selected_index {
+            Style::default()
+                .bg(common::theme::color(_config, "selection_bg"))
+                .fg(common::theme::color(_config, "selection_fg"))
+        } else {
+            Style::default()
+        };
+
+        items.push(ListItem::new(Line::from(spans)).style(style));
+    }
+
+    if switcher.items.is_empty() {
+        items.push(ListItem::new(Line::styled("No matches", detail)));
+    }
+
+    let width = min(SWITCHER_WIDTH, rect.width.saturating_sub(4));
+    let height = min(items.len() as u16 + 2, rect.height.saturating_sub(4));
+    let popup = Rect::new(
+        rect.x + rect.width.saturating_sub(width) / 2,
+        rect.y + 2,
+        width,
+        height,
+    );
+
+    frame.render_widget(Clear, popup);
+
+    if let Widgets::Block(block) = common::block::build(_config, true, &_context.mode) {
+        frame.render_widget(List::new(items).block(block.title("Switch to")), popup);
+    }
+
+    frame.set_cursor_position(Position::new(
+        popup.x + 3 + wrap::width(&switcher.query) as u16,
+        popup.y + 1,
+    ));
+}
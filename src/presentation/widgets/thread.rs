@@ -0,0 +1,199 @@
+use std::cmp::min;
+
+use crossterm::event::{self, KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{layout::Rect, style::Style, text::Line, widgets::Paragraph, Frame};
+
+use crate::{
+    cache::Cache,
+    common::enums::request::Request,
+    context::Context,
+    entities::{configuration::Configuration, slack::messages::Message},
+    enums::widgets::Widgets,
+    states::ThreadState,
+    utils::{self, sequence::Binding},
+};
+
+use super::{
+    common::{self, message},
+    messages, Widget,
+};
+
+pub fn get<'widget>() -> Widget<'widget> {
+    Widget {
+        commands,
+        keymaps,
+        sequences,
+        build,
+    }
+}
+
+fn commands(_config: &Configuration, command: &str, context: &mut Context) -> Option<Request> {
+    let state = &context.state.thread;
+    let len = state.messages.len();
+    if let Some(index) = common::list::moved(command, state.selected_index, len) {
+        select(context, index);
+        return None;
+    }
+
+    match command {
+        "open links" => {
+            if let Some(message) = context.state.thread.selected.clone() {
+                context.state.global.error = common::block_kit::open_links(&message).err();
+            }
+        }
+        "close thread" => {
+            close(context);
+        }
+        "edit message" => {
+            return messages::edit(context, context.state.thread.selected.clone());
+        }
+        _ => {}
+    }
+    None
+}
+
+pub fn open(context: &mut Context) -> Option<Request> {
+    let channel_id = context.state.channel.opened.clone()?.id;
+    let selected = context.state.message.selected.clone()?;
+    let ts = selected.thread_ts.clone().unwrap_or(selected.ts.clone());
+
+    let parent = context
+        .state
+        .message
+        .messages
+        .iter()
+        .find(|message| message.ts == ts)
+        .cloned()
+        .unwrap_or(Message {
+            ts: ts.clone(),
+            ..selected
+        });
+
+    context.state.message.opened = Some(parent);
+    context.state.thread = ThreadState::new();
+    context
+        .state
+        .input
+        .open(Some(format!("{}.{}", channel_id, ts)));
+    context.set_focus(String::from("thread"));
+
+    Some(Request::GetConversationReplies)
+}
+
+fn close(context: &mut Context) {
+    context.state.message.opened = None;
+    context.state.thread = ThreadState::new();
+    context.state.input.open(
+        context
+            .state
+            .channel
+            .opened
+            .clone()
+            .map(|channel| channel.id),
+    );
+    context.set_focus(String::from("messages"));
+}
+
+fn select(context: &mut Context, index: Option<usize>) {
+    let thread = &mut context.state.thread;
+    thread.selected_index = index;
+    thread.selected = index.and_then(|index| thread.messages.get(index).cloned());
+}
+
+pub fn sequences(_config: &Configuration, _context: &Context) -> Vec<Binding> {
+    vec![
+        Binding::new(_config.keymaps.top.clone(), "move top"),
+        Binding::new(_config.keymaps.bottom.clone(), "move bottom"),
+        Binding::new(_config.keymaps.links.clone(), "open links"),
+    ]
+}
+
+pub fn keymaps(
+    _config: &Configuration,
+    event: &event::Event,
+    _context: &mut Context,
+) -> Option<String> {
+    let up = utils::keycode::from_string(_config.keymaps.up.clone());
+    let down = utils::keycode::from_string(_config.keymaps.down.clone());
+    let editor = utils::keycode::from_string(_config.keymaps.editor.clone());
+
+    if let event::Event::Key(KeyEvent {
+        modifiers, code, ..
+    }) = event.clone()
+    {
+        match (modifiers, code) {
+            key if key == up => {
+                return Some(String::from("move up 1"));
+            }
+            key if key == down => {
+                return Some(String::from("move down 1"));
+            }
+            key if key == editor && _context.state.thread.selected.is_some() => {
+                return Some(String::from("edit message"));
+            }
+            (KeyModifiers::NONE, KeyCode::Esc) => {
+                return Some(String::from("close thread"));
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+pub fn build(
+    _config: &Configuration,
+    frame: &mut Frame,
+    _context: &Context,
+    _cache: &mut Cache,
+    rect: Rect,
+) {
+    let is_focus = _context.is_focus(&String::from("thread"));
+    let width = rect.width.saturating_sub(2) as usize;
+    let height = rect.height.saturating_sub(2) as usize;
+
+    let thread_state = &_context.state.thread;
+    let selected_style = Style::default().bg(common::theme::color(_config, "highlight_bg"));
+
+    let mut lines: Vec<Line> = Vec::new();
+    let mut selected_range: Option<(usize, usize)> = None;
+
+    for (index, message) in thread_state.messages.iter().enumerate() {
+        if index == 1 {
+            let replies = thread_state.messages.len() - 1;
+            let label = format!(
+                "{} {}",
+                replies,
+                if replies == 1 { "reply" } else { "replies" }
+            );
+            lines.push(message::separator(_config, &label, width));
+        }
+
+        let selected = thread_state.selected_index == Some(index);
+        let message_lines =
+            message::build(message, _config, &_context.state, _cache, width, selected);
+        let start = lines.len();
+
+        if selected {
+            selected_range = Some((start, start + message_lines.len()));
+            lines.extend(
+                message_lines
+                    .into_iter()
+                    .map(|line| line.patch_style(selected_style)),
+            );
+        } else {
+            lines.extend(message_lines);
+        }
+    }
+
+    let skip = match selected_range {
+        Some((start, end)) => min(start, end.saturating_sub(height)),
+        None => lines.len().saturating_sub(height),
+    };
+
+    let visible: Vec<Line> = lines.into_iter().skip(skip).take(height).collect();
+
+    if let Widgets::Block(block) = common::block::build(_config, is_focus, &_context.mode) {
+        frame.render_widget(Paragraph::new(visible).block(block.title("Thread")), rect);
+    }
+}
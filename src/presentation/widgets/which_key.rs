@@ -0,0 +1,82 @@
+use std::cmp::min;
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Clear, List, ListItem},
+    Frame,
+};
+
+use crate::{
+    context::Context,
+    entities::configuration::Configuration,
+    enums::widgets::Widgets,
+    utils::{
+        keycode,
+        sequence::{self, Binding},
+        wrap,
+    },
+};
+
+use super::common;
+
+const WHICH_KEY_WIDTH: u16 = 40;
+
+pub fn render(
+    frame: &mut Frame,
+    rect: Rect,
+    config: &Configuration,
+    context: &Context,
+    bindings: &[Binding],
+) {
+    let state = &context.state.sequence;
+    if state.pending.is_empty() {
+        return;
+    }
+
+    let continuations = sequence::continuations(bindings, &state.pending);
+    if continuations.is_empty() {
+        return;
+    }
+
+    let key_style = Style::default()
+        .fg(common::theme::color(config, "match"))
+        .add_modifier(Modifier::BOLD);
+    let key_width = continuations
+        .iter()
+        .map(|(keys, _)| wrap::width(keys))
+        .max()
+        .unwrap_or(0);
+
+    let items: Vec<ListItem> = continuations
+        .into_iter()
+        .map(|(keys, command)| {
+            let padding = " ".repeat(key_width - wrap::width(&keys));
+            ListItem::new(Line::from(vec![
+                Span::styled(keys, key_style),
+                Span::raw(format!("{}  {}", padding, command)),
+            ]))
+        })
+        .collect();
+
+    let mut title: Vec<String> = state.pending.iter().map(keycode::to_string).collect();
+    if let Some(count) = state.count {
+        title.insert(0, count.to_string());
+    }
+
+    let width = min(WHICH_KEY_WIDTH, rect.width);
+    let height = min(items.len() as u16 + 2, rect.height);
+    let popup = Rect::new(
+        rect.x + rect.width - width,
+        rect.y + rect.height - height,
+        width,
+        height,
+    );
+
+    frame.render_widget(Clear, popup);
+
+    if let Widgets::Block(block) = common::block::build(config, true, &context.mode) {
+        frame.render_widget(List::new(items).block(block.title(title.join(" "))), popup);
+    }
+}
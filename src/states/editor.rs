@@ -0,0 +1,530 @@
+use std::sync::Arc;
+
+const HISTORY_LIMIT: usize = 100;
+
+// Snapshots are shared so cloning the context doesn't copy the whole history
+type Snapshot = (Arc<str>, usize);
+
+#[derive(Clone, PartialEq, Default)]
+pub struct Editor {
+    value: String,
+    cursor: usize,
+    kill_ring: String,
+    undo: Vec<Snapshot>,
+    redo: Vec<Snapshot>,
+    typing: bool,
+}
+
+#[derive(PartialEq)]
+enum Class {
+    Space,
+    Word,
+    Punctuation,
+}
+
+impl Editor {
+    pub fn new() -> Editor {
+        Editor::default()
+    }
+
+    pub fn with_value(value: String) -> Editor {
+        Editor {
+            cursor: value.len(),
+            value,
+            ..Editor::default()
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn before_cursor(&self) -> &str {
+        &self.value[..self.cursor]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+
+    pub fn set(&mut self, value: String) {
+        self.checkpoint();
+        self.cursor = value.len();
+        self.value = value;
+    }
+
+    pub fn clear(&mut self) {
+        self.set(String::new());
+    }
+
+    pub fn insert(&mut self, text: &str) {
+        let single = text.chars().count() == 1 && !text.chars().any(char::is_whitespace);
+        if !(single && self.typing) {
+            self.checkpoint();
+        }
+        self.typing = single;
+
+        self.value.insert_str(self.cursor, text);
+        self.cursor += text.len();
+    }
+
+    pub fn replace_before_cursor(&mut self, length: usize, text: &str) {
+        self.checkpoint();
+        let start = self.cursor - length;
+        self.value.replace_range(start..self.cursor, text);
+        self.cursor = start + text.len();
+    }
+
+    pub fn delete_backward(&mut self) {
+        if let Some(start) = self.previous(self.cursor) {
+            self.checkpoint();
+            self.value.replace_range(start..self.cursor, "");
+            self.cursor = start;
+        }
+    }
+
+    pub fn delete_forward(&mut self) {
+        if let Some(end) = self.next(self.cursor) {
+            self.checkpoint();
+            self.value.replace_range(self.cursor..end, "");
+        }
+    }
+
+    pub fn move_left(&mut self) {
+        self.jump(self.previous(self.cursor).unwrap_or(self.cursor));
+    }
+
+    pub fn move_right(&mut self) {
+        self.jump(self.next(self.cursor).unwrap_or(self.cursor));
+    }
+
+    pub fn move_word_backward(&mut self) {
+        self.jump(self.word_backward());
+    }
+
+    pub fn move_word_forward(&mut self) {
+        self.jump(self.word_forward());
+    }
+
+    pub fn move_word_end(&mut self) {
+        self.jump(self.word_end());
+    }
+
+    pub fn move_line_start(&mut self) {
+        self.jump(self.line_start(self.cursor));
+    }
+
+    pub fn move_line_first_non_blank(&mut self) {
+        let start = self.line_start(self.cursor);
+        let end = self.line_end(self.cursor);
+        let offset = self.value[start..end]
+            .find(|char: char| !char.is_whitespace())
+            .unwrap_or(end - start);
+        self.jump(start + offset);
+    }
+
+    pub fn move_line_end(&mut self) {
+        self.jump(self.line_end(self.cursor));
+    }
+
+    pub fn move_start(&mut self) {
+        self.jump(0);
+    }
+
+    pub fn move_end(&mut self) {
+        self.jump(self.value.len());
+    }
+
+    pub fn move_up(&mut self) {
+        let start = self.line_start(self.cursor);
+        if start > 0 {
+            let column = self.value[start..self.cursor].chars().count();
+            let target = self.line_start(start - 1);
+            self.jump(self.column(target, column));
+        }
+    }
+
+    pub fn move_down(&mut self) {
+        let end = self.line_end(self.cursor);
+        if end < self.value.len() {
+            let column = self.value[self.line_start(self.cursor)..self.cursor]
+                .chars()
+                .count();
+            self.jump(self.column(end + 1, column));
+        }
+    }
+
+    pub fn open_line_below(&mut self) {
+        self.move_line_end();
+        self.checkpoint();
+        self.value.insert(self.cursor, '\n');
+        self.cursor += 1;
+    }
+
+    pub fn open_line_above(&mut self) {
+        self.move_line_start();
+        self.checkpoint();
+        self.value.insert(self.cursor, '\n');
+    }
+
+    pub fn kill_line_end(&mut self) {
+        let end = self.line_end(self.cursor);
+        let end = if end == self.cursor {
+            self.next(end).unwrap_or(end)
+        } else {
+            end
+        };
+        self.kill(self.cursor, end);
+    }
+
+    pub fn kill_line(&mut self) {
+        let start = self.line_start(self.cursor);
+        let end = self.line_end(self.cursor);
+        if end < self.value.len() {
+            self.kill(start, end + 1);
+        } else {
+            self.kill(start.saturating_sub(1), end);
+        }
+    }
+
+    pub fn kill_line_start(&mut self) {
+        self.kill(self.line_start(self.cursor), self.cursor);
+    }
+
+    pub fn kill_word_backward(&mut self) {
+        self.kill(self.word_backward(), self.cursor);
+    }
+
+    pub fn kill_word_forward(&mut self) {
+        self.kill(self.cursor, self.word_forward());
+    }
+
+    pub fn kill_char_forward(&mut self) {
+        if let Some(end) = self.next(self.cursor) {
+            self.kill(self.cursor, end);
+        }
+    }
+
+    pub fn kill_char_backward(&mut self) {
+        if let Some(start) = self.previous(self.cursor) {
+            self.kill(start, self.cursor);
+        }
+    }
+
+    pub fn yank(&mut self) {
+        if !self.kill_ring.is_empty() {
+            let text = self.kill_ring.clone();
+            self.checkpoint();
+            self.value.insert_str(self.cursor, &text);
+            self.cursor += text.len();
+        }
+    }
+
+    pub fn yank_after(&mut self) {
+        if !self.kill_ring.is_empty() {
+            self.move_right();
+            self.yank();
+        }
+    }
+
+    pub fn undo(&mut self) {
+        if let Some((value, cursor)) = self.undo.pop() {
+            let snapshot = self.snapshot();
+            push(&mut self.redo, snapshot);
+            self.value = value.to_string();
+            self.cursor = cursor;
+            self.typing = false;
+        }
+    }
+
+    pub fn redo(&mut self) {
+        if let Some((value, cursor)) = self.redo.pop() {
+            let snapshot = self.snapshot();
+            push(&mut self.undo, snapshot);
+            self.value = value.to_string();
+            self.cursor = cursor;
+            self.typing = false;
+        }
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        (Arc::from(self.value.as_str()), self.cursor)
+    }
+
+    fn checkpoint(&mut self) {
+        let snapshot = self.snapshot();
+        push(&mut self.undo, snapshot);
+        self.redo.clear();
+        self.typing = false;
+    }
+
+    fn kill(&mut self, start: usize, end: usize) {
+        if start < end {
+            self.checkpoint();
+            self.kill_ring = self.value[start..end].to_string();
+            self.value.replace_range(start..end, "");
+            self.cursor = start;
+        }
+    }
+
+    fn jump(&mut self, cursor: usize) {
+        self.cursor = cursor;
+        self.typing = false;
+    }
+
+    fn previous(&self, index: usize) -> Option<usize> {
+        self.value[..index]
+            .chars()
+            .next_back()
+            .map(|char| index - char.len_utf8())
+    }
+
+    fn next(&self, index: usize) -> Option<usize> {
+        self.value[index..]
+            .chars()
+            .next()
+            .map(|char| index + char.len_utf8())
+    }
+
+    fn line_start(&self, index: usize) -> usize {
+        self.value[..index].rfind('\n').map_or(0, |index| index + 1)
+    }
+
+    fn line_end(&self, index: usize) -> usize {
+        self.value[index..]
+            .find('\n')
+            .map_or(self.value.len(), |offset| index + offset)
+    }
+
+    fn column(&self, start: usize, column: usize) -> usize {
+        let end = self.line_end(start);
+        self.value[start..end]
+            .char_indices()
+            .nth(column)
+            .map_or(end, |(offset, _)| start + offset)
+    }
+
+    fn class(char: char) -> Class {
+        if char.is_whitespace() {
+            Class::Space
+        } else if char.is_alphanumeric() || char == '_' {
+            Class::Word
+        } else {
+            Class::Punctuation
+        }
+    }
+
+    fn word_backward(&self) -> usize {
+        let mut chars = self.value[..self.cursor].char_indices().rev().peekable();
+
+        while chars
+            .peek()
+            .is_some_and(|(_, char)| Editor::class(*char) == Class::Space)
+        {
+            chars.next();
+        }
+
+        let mut index = chars.peek().map_or(0, |(index, _)| *index);
+
+        if let Some((_, first)) = chars.next() {
+            let class = Editor::class(first);
+            for (previous, char) in chars {
+                if Editor::class(char) != class {
+                    break;
+                }
+                index = previous;
+            }
+        }
+
+        index
+    }
+
+    fn word_forward(&self) -> usize {
+        let mut chars = self.value[self.cursor..].char_indices().peekable();
+
+        if let Some((_, first)) = chars.peek().copied() {
+            let class = Editor::class(first);
+            if class != Class::Space {
+                while chars
+                    .peek()
+                    .is_some_and(|(_, char)| Editor::class(*char) == class)
+                {
+                    chars.next();
+                }
+            }
+        }
+
+        while chars
+            .peek()
+            .is_some_and(|(_, char)| Editor::class(*char) == Class::Space)
+        {
+            chars.next();
+        }
+
+        chars
+            .peek()
+            .map_or(self.value.len(), |(offset, _)| self.cursor + offset)
+    }
+
+    fn word_end(&self) -> usize {
+        let start = self.next(self.cursor).unwrap_or(self.cursor);
+        let mut chars = self.value[start..].char_indices().peekable();
+
+        while chars
+            .peek()
+            .is_some_and(|(_, char)| Editor::class(*char) == Class::Space)
+        {
+            chars.next();
+        }
+
+        let mut end = chars
+            .peek()
+            .map_or(self.cursor, |(offset, _)| start + offset);
+
+        if let Some((_, first)) = chars.next() {
+            let class = Editor::class(first);
+            for (offset, char) in chars {
+                if Editor::class(char) != class {
+                    break;
+                }
+                end = start + offset;
+            }
+        }
+
+        end
+    }
+}
+
+fn push(history: &mut Vec<Snapshot>, snapshot: Snapshot) {
+    if history.len() >= HISTORY_LIMIT {
+        history.remove(0);
+    }
+    history.push(snapshot);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Editor, HISTORY_LIMIT};
+
+    type Edit = fn(&mut Editor);
+
+    // `|` marks the cursor
+    fn editor(marked: &str) -> Editor {
+        let cursor = marked.find('|').unwrap();
+        Editor {
+            value: marked.replacen('|', "", 1),
+            cursor,
+            ..Editor::default()
+        }
+    }
+
+    fn marked(editor: &Editor) -> String {
+        format!(
+            "{}|{}",
+            &editor.value[..editor.cursor],
+            &editor.value[editor.cursor..]
+        )
+    }
+
+    #[test]
+    fn motions_step_over_multibyte_text() {
+        let cases: [(&str, Edit, &str); 16] = [
+            ("a|é", Editor::move_right, "aé|"),
+            ("aé|", Editor::move_left, "a|é"),
+            ("👍|x", Editor::move_left, "|👍x"),
+            ("|", Editor::move_left, "|"),
+            ("|héllo wörld", Editor::move_word_forward, "héllo |wörld"),
+            ("héllo wörld|", Editor::move_word_backward, "héllo |wörld"),
+            ("|foo.bar baz", Editor::move_word_forward, "foo|.bar baz"),
+            ("foo.|bar", Editor::move_word_backward, "foo|.bar"),
+            ("|föo.bar", Editor::move_word_end, "fö|o.bar"),
+            ("fö|o  bär", Editor::move_word_end, "föo  bä|r"),
+            ("ab\nc|déf", Editor::move_up, "a|b\ncdéf"),
+            ("日本|語\nx", Editor::move_down, "日本語\nx|"),
+            ("a|b\n日本語", Editor::move_down, "ab\n日|本語"),
+            ("ab\n  é|x", Editor::move_line_first_non_blank, "ab\n  |éx"),
+            ("a\n日本|語\nb", Editor::move_line_start, "a\n|日本語\nb"),
+            ("a\n日|本語\nb", Editor::move_line_end, "a\n日本語|\nb"),
+        ];
+
+        for (before, motion, expected) in cases {
+            let mut editor = editor(before);
+            motion(&mut editor);
+            assert_eq!(marked(&editor), expected, "{}", before);
+        }
+    }
+
+    #[test]
+    fn kills_round_trip_through_the_kill_ring() {
+        let cases: [(&str, Edit, &str, &str); 7] = [
+            (
+                "héllo |wörld",
+                Editor::kill_word_forward,
+                "héllo |",
+                "héllo wörld|",
+            ),
+            (
+                "héllo wörld|",
+                Editor::kill_word_backward,
+                "héllo |",
+                "héllo wörld|",
+            ),
+            ("aé|b", Editor::kill_char_backward, "a|b", "aé|b"),
+            ("a|éb", Editor::kill_char_forward, "a|b", "aé|b"),
+            ("ab|çd", Editor::kill_line_end, "ab|", "abçd|"),
+            ("ab|\ncd", Editor::kill_line_end, "ab|cd", "ab\n|cd"),
+            ("a\nb|ç\nd", Editor::kill_line, "a\n|d", "a\nbç\n|d"),
+        ];
+
+        for (before, kill, killed, yanked) in cases {
+            let mut editor = editor(before);
+            kill(&mut editor);
+            assert_eq!(marked(&editor), killed, "{}", before);
+            editor.yank();
+            assert_eq!(marked(&editor), yanked, "{}", before);
+        }
+    }
+
+    #[test]
+    fn typing_is_undone_as_one_step() {
+        let mut editor = Editor::new();
+        for text in ["h", "é", "y", " ", "y", "o"] {
+            editor.insert(text);
+        }
+
+        editor.undo();
+        assert_eq!(marked(&editor), "héy |");
+        editor.undo();
+        assert_eq!(marked(&editor), "héy|");
+        editor.undo();
+        assert_eq!(marked(&editor), "|");
+        editor.redo();
+        editor.redo();
+        assert_eq!(marked(&editor), "héy |");
+    }
+
+    #[test]
+    fn undo_and_redo_stop_at_the_history_limit() {
+        let edits = HISTORY_LIMIT + 50;
+        let mut editor = Editor::new();
+        for index in 1..=edits {
+            editor.set(index.to_string());
+        }
+
+        for _ in 0..edits {
+            editor.undo();
+        }
+        assert_eq!(editor.value(), (edits - HISTORY_LIMIT).to_string());
+        assert_eq!(editor.redo.len(), HISTORY_LIMIT);
+
+        for _ in 0..edits {
+            editor.redo();
+        }
+        assert_eq!(editor.value(), edits.to_string());
+        assert_eq!(editor.undo.len(), HISTORY_LIMIT);
+
+        editor.undo();
+        editor.insert("x");
+        assert!(editor.redo.is_empty());
+    }
+}
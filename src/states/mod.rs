@@ -1,8 +1,16 @@
+pub mod editor;
+
+use std::{collections::HashMap, time::Instant};
+
+use crossterm::event::{KeyCode, KeyModifiers};
+
 use crate::{
     entities::slack::{
-        authorization::Authorization, conversations::Channel, messages::Message, users::Member,
+        authorization::Authorization, conversations::Channel, messages::Message,
+        usergroups::Usergroup, users::Member,
     },
-    enums::{section::Section, user_mode::UserMode},
+    enums::{connection::Connection, section::Section, user_mode::UserMode},
+    states::editor::Editor,
 };
 
 #[derive(Clone, PartialEq)]
@@ -12,19 +20,46 @@ pub struct State {
     pub message: MessageState,
     pub input: InputState,
     pub thread: ThreadState,
+    pub layout: LayoutState,
+    pub sequence: SequenceState,
+    pub command_line: CommandLineState,
 }
 
 #[derive(Clone, PartialEq)]
 pub struct GlobalState {
     pub members: Vec<Member>,
+    pub usergroups: Vec<Usergroup>,
+    pub emojis: HashMap<String, String>,
+    pub missing_scopes: Vec<String>,
     pub section: Section,
     pub exit: bool,
+    pub presence: Option<String>,
+    pub connection: Connection,
+    pub last_sync: Option<i64>,
+    pub error: Option<String>,
 }
 
 #[derive(Clone, PartialEq)]
 pub struct InputState {
-    pub value: String,
+    pub editor: Editor,
+    pub drafts: HashMap<String, String>,
+    pub draft_key: Option<String>,
     pub send: bool,
+    pub completion: Option<Completion>,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct Completion {
+    pub items: Vec<CompletionItem>,
+    pub selected_index: usize,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct CompletionItem {
+    pub label: String,
+    pub detail: String,
+    pub value: String,
+    pub indices: Vec<usize>,
 }
 
 #[derive(Clone, PartialEq)]
@@ -34,7 +69,18 @@ pub struct ChannelState {
     pub selected: Option<Channel>,
     pub selected_index: Option<usize>,
     pub opened: Option<Channel>,
-    pub search: String,
+    pub recent: Vec<String>,
+    pub starred: Vec<String>,
+    pub collapsed: Vec<String>,
+    pub switcher: Option<Switcher>,
+    pub mentions: HashMap<String, u32>,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct Switcher {
+    pub query: String,
+    pub items: Vec<CompletionItem>,
+    pub selected_index: usize,
 }
 
 #[derive(Clone, PartialEq)]
@@ -50,6 +96,32 @@ pub struct ThreadState {
     pub messages: Vec<Message>,
     pub selected: Option<Message>,
     pub selected_index: Option<usize>,
+    pub broadcast: bool,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct LayoutState {
+    pub sidebar_width: Option<u16>,
+    pub thread_width: Option<u16>,
+    pub composer_height: Option<u16>,
+    pub sidebar_hidden: bool,
+    pub zoom: bool,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct SequenceState {
+    pub pending: Vec<(KeyModifiers, KeyCode)>,
+    pub count: Option<usize>,
+    pub since: Option<Instant>,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct CommandLineState {
+    pub editor: Option<Editor>,
+    pub history: Vec<String>,
+    pub history_index: Option<usize>,
+    pub completion: Option<Completion>,
+    pub error: Option<String>,
 }
 
 impl State {
@@ -60,6 +132,9 @@ impl State {
             message: MessageState::new(),
             input: InputState::new(),
             thread: ThreadState::new(),
+            layout: LayoutState::new(),
+            sequence: SequenceState::new(),
+            command_line: CommandLineState::new(),
         }
     }
 }
@@ -68,8 +143,15 @@ impl GlobalState {
     pub fn new() -> GlobalState {
         GlobalState {
             members: Vec::new(),
+            usergroups: Vec::new(),
+            emojis: HashMap::new(),
+            missing_scopes: Vec::new(),
             section: Section::Channel,
             exit: false,
+            presence: None,
+            connection: Connection::Connecting,
+            last_sync: None,
+            error: None,
         }
     }
     pub fn get_user(&self, id: String) -> Option<Member> {
@@ -79,6 +161,12 @@ impl GlobalState {
             .next()
             .map_or(None, |user| Some(user.clone()))
     }
+    pub fn get_usergroup(&self, id: String) -> Option<Usergroup> {
+        self.usergroups
+            .iter()
+            .find(|usergroup| usergroup.id == id)
+            .cloned()
+    }
 }
 
 impl ChannelState {
@@ -89,7 +177,54 @@ impl ChannelState {
             selected: None,
             selected_index: None,
             opened: None,
-            search: String::new(),
+            recent: Vec::new(),
+            starred: Vec::new(),
+            collapsed: Vec::new(),
+            switcher: None,
+            mentions: HashMap::new(),
+        }
+    }
+    pub fn get_channel(&self, id: String) -> Option<Channel> {
+        self.channels
+            .iter()
+            .chain(self.direct_messages.iter())
+            .find(|channel| channel.id == id)
+            .cloned()
+    }
+    pub fn set_unread(&mut self, id: &str, count: u32) {
+        let channels = self
+            .channels
+            .iter_mut()
+            .chain(self.direct_messages.iter_mut())
+            .chain(self.opened.iter_mut())
+            .chain(self.selected.iter_mut());
+
+        for channel in channels.filter(|channel| channel.id == id) {
+            channel.unread_count_display = Some(count);
+        }
+    }
+    pub fn set_mentions(&mut self, id: &str, count: u32) {
+        if count == 0 {
+            self.mentions.remove(id);
+        } else {
+            self.mentions.insert(id.to_string(), count);
+        }
+    }
+    pub fn is_starred(&self, id: &str) -> bool {
+        self.starred.iter().any(|starred| starred == id)
+    }
+    pub fn toggle_star(&mut self, id: &str) {
+        if self.is_starred(id) {
+            self.starred.retain(|starred| starred != id);
+        } else {
+            self.starred.push(id.to_string());
+        }
+    }
+    pub fn toggle_section(&mut self, name: &str) {
+        if self.collapsed.iter().any(|collapsed| collapsed == name) {
+            self.collapsed.retain(|collapsed| collapsed != name);
+        } else {
+            self.collapsed.push(name.to_string());
         }
     }
 }
@@ -103,15 +238,68 @@ impl MessageState {
             opened: None,
         }
     }
+    pub fn refresh_selected(&mut self) {
+        (self.selected_index, self.selected) = reselect(&self.messages, &self.selected);
+
+        if let Some(opened) = self.opened.as_mut() {
+            if let Some(message) = self.messages.iter().find(|message| message.ts == opened.ts) {
+                *opened = message.clone();
+            }
+        }
+    }
 }
 
 impl InputState {
     pub fn new() -> InputState {
         InputState {
-            value: String::new(),
+            editor: Editor::new(),
+            drafts: HashMap::new(),
+            draft_key: None,
             send: false,
+            completion: None,
+        }
+    }
+    pub fn open(&mut self, key: Option<String>) {
+        self.save_draft();
+
+        let draft = key.clone().and_then(|key| self.drafts.get(&key).cloned());
+        match (draft, &self.draft_key) {
+            (Some(draft), _) => self.editor = Editor::with_value(draft),
+            (None, Some(_)) => self.editor = Editor::new(),
+            (None, None) => {}
+        }
+
+        self.draft_key = key;
+        self.completion = None;
+        self.save_draft();
+    }
+    pub fn save_draft(&mut self) {
+        if let Some(key) = self.draft_key.clone() {
+            if self.editor.is_empty() {
+                self.drafts.remove(&key);
+            } else {
+                self.drafts.insert(key, self.editor.value().to_string());
+            }
+        }
+    }
+    pub fn sent(&mut self, key: &str, text: &str) {
+        if self.draft_key.as_deref() == Some(key) && self.editor.value().trim_end() == text {
+            self.editor.clear();
+            self.completion = None;
+        }
+        if self
+            .drafts
+            .get(key)
+            .is_some_and(|draft| draft.trim_end() == text)
+        {
+            self.drafts.remove(key);
         }
     }
+    pub fn has_draft(&self, channel_id: &str) -> bool {
+        self.drafts
+            .keys()
+            .any(|key| key == channel_id || key.starts_with(&format!("{}.", channel_id)))
+    }
 }
 
 impl ThreadState {
@@ -120,6 +308,64 @@ impl ThreadState {
             messages: Vec::new(),
             selected: None,
             selected_index: None,
+            broadcast: false,
         }
     }
+    pub fn refresh_selected(&mut self) {
+        (self.selected_index, self.selected) = reselect(&self.messages, &self.selected);
+    }
+}
+
+// Selection re-pointed at the refetched copy of the same message
+fn reselect(messages: &[Message], selected: &Option<Message>) -> (Option<usize>, Option<Message>) {
+    let index = selected.as_ref().and_then(|selected| {
+        messages
+            .iter()
+            .position(|message| message.ts == selected.ts)
+    });
+
+    (index, index.and_then(|index| messages.get(index).cloned()))
+}
+
+impl LayoutState {
+    pub fn new() -> LayoutState {
+        LayoutState {
+            sidebar_width: None,
+            thread_width: None,
+            composer_height: None,
+            sidebar_hidden: false,
+            zoom: false,
+        }
+    }
+}
+
+impl SequenceState {
+    pub fn new() -> SequenceState {
+        SequenceState {
+            pending: Vec::new(),
+            count: None,
+            since: None,
+        }
+    }
+    pub fn is_pending(&self) -> bool {
+        !self.pending.is_empty() || self.count.is_some()
+    }
+    pub fn reset(&mut self) {
+        *self = SequenceState::new();
+    }
+}
+
+impl CommandLineState {
+    pub fn new() -> CommandLineState {
+        CommandLineState {
+            editor: None,
+            history: Vec::new(),
+            history_index: None,
+            completion: None,
+            error: None,
+        }
+    }
+    pub fn is_open(&self) -> bool {
+        self.editor.is_some()
+    }
 }
@@ -0,0 +1,163 @@
+use chrono::{
+    format::{Item, StrftimeItems},
+    DateTime, Duration, FixedOffset, Local, Offset, Utc,
+};
+
+use crate::entities::configuration::{Clock, Configuration};
+
+pub fn offset(
+    timezone: &str,
+) -> Result<Option<FixedOffset>, Box<dyn std::error::Error + Send + Sync>> {
+    let value = timezone.trim();
+
+    match value.to_lowercase().as_str() {
+        "local" => return Ok(None),
+        "utc" | "z" => return Ok(FixedOffset::east_opt(0)),
+        _ => {}
+    }
+
+    let value = value
+        .strip_prefix("UTC")
+        .or(value.strip_prefix("utc"))
+        .unwrap_or(value);
+    let (sign, rest) = match value.chars().next() {
+        Some('+') => (1, &value[1..]),
+        Some('-') => (-1, &value[1..]),
+        _ => return Err(format!("Invalid timezone: {}", timezone).into()),
+    };
+    let (hours, minutes) = match rest.split_once(':') {
+        Some((hours, minutes)) => (hours, minutes),
+        None if rest.len() == 4 && rest.is_ascii() => rest.split_at(2),
+        None => (rest, "0"),
+    };
+
+    let invalid = || format!("Invalid timezone: {}", timezone);
+    let hours: i32 = hours.parse().map_err(|_| invalid())?;
+    let minutes: i32 = minutes.parse().map_err(|_| invalid())?;
+
+    Ok(Some(
+        FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).ok_or(invalid())?,
+    ))
+}
+
+pub fn validate(format: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    if StrftimeItems::new(format).any(|item| item == Item::Error) {
+        return Err(format!("Invalid format: {}", format).into());
+    }
+
+    Ok(())
+}
+
+pub fn now(config: &Configuration) -> DateTime<FixedOffset> {
+    convert(config, Utc::now())
+}
+
+pub fn parse(config: &Configuration, timestamp: &str) -> Option<DateTime<FixedOffset>> {
+    let seconds = timestamp.parse::<f64>().ok()?.floor() as i64;
+    from_timestamp(config, seconds)
+}
+
+pub fn from_timestamp(config: &Configuration, seconds: i64) -> Option<DateTime<FixedOffset>> {
+    DateTime::from_timestamp(seconds, 0).map(|datetime| convert(config, datetime))
+}
+
+pub fn time(config: &Configuration, datetime: &DateTime<FixedOffset>) -> String {
+    let format = match config.datetime.clock {
+        Clock::Hour24 => &config.datetime.time_format,
+        Clock::Hour12 => &config.datetime.time_format_12h,
+    };
+
+    datetime.format(format).to_string()
+}
+
+pub fn relative_time(config: &Configuration, datetime: &DateTime<FixedOffset>) -> String {
+    if !config.datetime.relative_times {
+        return time(config, datetime);
+    }
+
+    let elapsed = now(config).signed_duration_since(datetime);
+
+    if elapsed < Duration::zero() || elapsed >= Duration::days(1) {
+        time(config, datetime)
+    } else if elapsed < Duration::minutes(1) {
+        String::from("just now")
+    } else if elapsed < Duration::hours(1) {
+        format!("{}m ago", elapsed.num_minutes())
+    } else {
+        format!("{}h ago", elapsed.num_hours())
+    }
+}
+
+pub fn date(config: &Configuration, datetime: &DateTime<FixedOffset>) -> String {
+    if config.datetime.relative_dates {
+        let today = now(config).date_naive();
+        let date = datetime.date_naive();
+
+        if date == today {
+            return String::from("Today");
+        }
+        if today.pred_opt() == Some(date) {
+            return String::from("Yesterday");
+        }
+    }
+
+    datetime.format(&config.datetime.date_format).to_string()
+}
+
+pub fn full(config: &Configuration, datetime: &DateTime<FixedOffset>) -> String {
+    datetime.format(&config.datetime.full_format).to_string()
+}
+
+fn convert(config: &Configuration, datetime: DateTime<Utc>) -> DateTime<FixedOffset> {
+    match config.datetime.offset {
+        Some(offset) => datetime.with_timezone(&offset),
+        None => {
+            let local = datetime.with_timezone(&Local);
+            local.with_timezone(&local.offset().fix())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::FixedOffset;
+
+    use super::{offset, validate};
+
+    #[test]
+    fn offset_parses_utc_offsets() {
+        let hours = |hours: i32| FixedOffset::east_opt(hours * 3600);
+        let cases = [
+            ("local", Some(None)),
+            ("Local", Some(None)),
+            ("utc", Some(hours(0))),
+            ("Z", Some(hours(0))),
+            ("+02:00", Some(hours(2))),
+            ("-0530", Some(FixedOffset::west_opt(5 * 3600 + 30 * 60))),
+            ("UTC+9", Some(hours(9))),
+            ("utc-3", Some(hours(-3))),
+            (" +01:00 ", Some(hours(1))),
+            ("+24:00", None),
+            ("Europe/Paris", None),
+            ("+ab", None),
+            ("+aé1", None),
+            ("utc+é", None),
+            ("+99", None),
+            ("", None),
+        ];
+
+        for (timezone, expected) in cases {
+            assert_eq!(offset(timezone).ok(), expected, "{:?}", timezone);
+        }
+    }
+
+    #[test]
+    fn validate_rejects_unknown_specifiers() {
+        for format in ["%H:%M", "%-I:%M %p", "%A, %B %d %Y %H:%M:%S", "plain"] {
+            assert!(validate(format).is_ok(), "{}", format);
+        }
+        for format in ["%Q", "%H:%", "%-"] {
+            assert!(validate(format).is_err(), "{}", format);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use std::{
+    env, fs,
+    io::{self, Write},
+    process::Command,
+};
+
+use crossterm::{
+    event::{
+        DisableBracketedPaste, EnableBracketedPaste, KeyboardEnhancementFlags,
+        PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+    },
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+
+pub fn edit(text: &str) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+    // Created exclusively with a random name and removed when dropped
+    let mut file = tempfile::Builder::new()
+        .prefix("clack-")
+        .suffix(".md")
+        .tempfile()?;
+    file.write_all(text.as_bytes())?;
+    file.flush()?;
+
+    let editor = env::var("VISUAL")
+        .or(env::var("EDITOR"))
+        .unwrap_or(String::from("vi"));
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().unwrap_or("vi");
+
+    let _ = execute!(io::stdout(), PopKeyboardEnhancementFlags);
+    execute!(io::stdout(), DisableBracketedPaste, LeaveAlternateScreen)?;
+    disable_raw_mode()?;
+
+    let status = Command::new(program).args(parts).arg(file.path()).status();
+
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen, EnableBracketedPaste)?;
+    let _ = execute!(
+        io::stdout(),
+        PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES)
+    );
+
+    let status = status.map_err(|error| format!("{}: {}", program, error))?;
+    if !status.success() {
+        return Err(format!("{} exited with {}", program, status).into());
+    }
+
+    let content = fs::read_to_string(file.path())?;
+    Ok(content
+        .strip_suffix('\n')
+        .map_or(content.clone(), |content| content.to_string()))
+}
@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+
+use regex::{Captures, Regex};
+
+use crate::common::constants::emoji::{EMOJI, SKIN_TONES};
+
+pub fn get(name: &str, custom: &HashMap<String, String>) -> String {
+    let (base, tone) = match name.split_once("::") {
+        Some((base, tone)) => (
+            base,
+            tone.strip_prefix("skin-tone-")
+                .and_then(|tone| tone.parse::<usize>().ok())
+                .and_then(|tone| SKIN_TONES.get(tone.wrapping_sub(2))),
+        ),
+        None => (name, None),
+    };
+
+    match (resolve(base, custom), tone) {
+        (Some(unicode), Some(modifier)) => {
+            let mut chars = unicode.chars();
+            let first = chars.next().unwrap_or_default();
+            let rest = chars.as_str().trim_start_matches('\u{fe0f}');
+            format!("{}{}{}", first, modifier, rest)
+        }
+        (Some(unicode), None) => unicode,
+        (None, _) => format!(":{}:", name),
+    }
+}
+
+pub fn replace(text: &str, custom: &HashMap<String, String>) -> String {
+    let re = Regex::new(r":([a-z0-9_+'\-]+):(?::(skin-tone-[2-6]):)?").unwrap();
+
+    re.replace_all(text, |captures: &Captures| match captures.get(2) {
+        Some(tone) => get(&format!("{}::{}", &captures[1], tone.as_str()), custom),
+        None => get(&captures[1], custom),
+    })
+    .to_string()
+}
+
+fn resolve(name: &str, custom: &HashMap<String, String>) -> Option<String> {
+    let mut name = name.to_string();
+
+    for _ in 0..8 {
+        if let Ok(index) = EMOJI.binary_search_by_key(&name.as_str(), |(code, _)| code) {
+            return Some(EMOJI[index].1.to_string());
+        }
+
+        match custom
+            .get(&name)
+            .and_then(|value| value.strip_prefix("alias:"))
+        {
+            Some(alias) => name = alias.to_string(),
+            None => return None,
+        }
+    }
+
+    None
+}
@@ -0,0 +1,110 @@
+pub fn find(query: &str, candidate: &str) -> Option<(i64, Vec<usize>)> {
+    let query: Vec<char> = query.chars().collect();
+    let candidate: Vec<char> = candidate.chars().collect();
+
+    if query.is_empty() {
+        return Some((0, Vec::new()));
+    }
+
+    let mut best: Option<(i64, Vec<usize>)> = None;
+
+    for start in 0..candidate.len() {
+        if !same(candidate[start], query[0]) {
+            continue;
+        }
+
+        let mut indices = vec![start];
+        let mut position = start + 1;
+
+        for char in query.iter().skip(1) {
+            match (position..candidate.len()).find(|index| same(candidate[*index], *char)) {
+                Some(index) => {
+                    indices.push(index);
+                    position = index + 1;
+                }
+                None => break,
+            }
+        }
+
+        if indices.len() < query.len() {
+            break;
+        }
+
+        let score = score(&candidate, &indices);
+        if best.as_ref().is_none_or(|(best, _)| score > *best) {
+            best = Some((score, indices));
+        }
+    }
+
+    best
+}
+
+fn score(candidate: &[char], indices: &[usize]) -> i64 {
+    let mut score: i64 = 0;
+
+    for (position, index) in indices.iter().enumerate() {
+        score += 16;
+
+        if *index == 0 {
+            score += 20;
+        } else {
+            let previous = candidate[index - 1];
+            if matches!(previous, ' ' | '_' | '-' | '.' | '/')
+                || (previous.is_lowercase() && candidate[*index].is_uppercase())
+            {
+                score += 8;
+            }
+        }
+
+        if position > 0 {
+            let gap = index - indices[position - 1] - 1;
+            if gap == 0 {
+                score += 12;
+            } else {
+                score -= gap as i64;
+            }
+        }
+    }
+
+    score - candidate.len() as i64 / 4
+}
+
+fn same(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::find;
+
+    #[test]
+    fn find_matches_in_order() {
+        let cases: [(&str, &str, Option<Vec<usize>>); 6] = [
+            ("", "general", Some(vec![])),
+            ("gen", "general", Some(vec![0, 1, 2])),
+            ("GEN", "general", Some(vec![0, 1, 2])),
+            ("gl", "general", Some(vec![0, 6])),
+            ("lg", "general", None),
+            ("dev", "team-dev", Some(vec![5, 6, 7])),
+        ];
+
+        for (query, candidate, expected) in cases {
+            assert_eq!(
+                find(query, candidate).map(|(_, indices)| indices),
+                expected,
+                "{} in {}",
+                query,
+                candidate
+            );
+        }
+    }
+
+    #[test]
+    fn find_prefers_word_starts_and_runs() {
+        let score = |query: &str, candidate: &str| find(query, candidate).unwrap().0;
+
+        assert!(score("ra", "random") > score("ra", "library"));
+        assert!(score("dev", "dev-ops") > score("dev", "d-e-v"));
+        assert!(score("fb", "foo-bar") > score("fb", "foobar"));
+    }
+}
@@ -1,46 +1,320 @@
 use crossterm::event::{KeyCode, KeyModifiers};
 
-fn key_code_from_string(key: &str) -> KeyCode {
-    match key {
-        "down" => KeyCode::Down,
-        "up" => KeyCode::Up,
-        "left" => KeyCode::Left,
-        "right" => KeyCode::Right,
-        "enter" => KeyCode::Enter,
-        "backspace" => KeyCode::Backspace,
-        "delete" => KeyCode::Delete,
-        c if c.len() == 1 => match c.chars().next() {
-            Some(char) => KeyCode::Char(char),
-            None => panic!("key code not supported: {}", key),
-        },
-        _ => panic!("key code not supported: {}", key),
+type Key = (KeyModifiers, KeyCode);
+type Error = Box<dyn std::error::Error + Send + Sync>;
+
+const NAMED_KEYS: [(&str, KeyCode); 17] = [
+    ("down", KeyCode::Down),
+    ("up", KeyCode::Up),
+    ("left", KeyCode::Left),
+    ("right", KeyCode::Right),
+    ("enter", KeyCode::Enter),
+    ("backspace", KeyCode::Backspace),
+    ("delete", KeyCode::Delete),
+    ("tab", KeyCode::Tab),
+    ("backtab", KeyCode::BackTab),
+    ("esc", KeyCode::Esc),
+    ("space", KeyCode::Char(' ')),
+    ("home", KeyCode::Home),
+    ("end", KeyCode::End),
+    ("pageup", KeyCode::PageUp),
+    ("pagedown", KeyCode::PageDown),
+    ("insert", KeyCode::Insert),
+    ("lt", KeyCode::Char('<')),
+];
+
+const ALIASES: [(&str, &str); 8] = [
+    ("return", "enter"),
+    ("cr", "enter"),
+    ("bs", "backspace"),
+    ("del", "delete"),
+    ("escape", "esc"),
+    ("pgup", "pageup"),
+    ("pgdn", "pagedown"),
+    ("ins", "insert"),
+];
+
+fn key_code_from_string(key: &str) -> Result<KeyCode, Error> {
+    let mut chars = key.chars();
+    if let (Some(char), None) = (chars.next(), chars.next()) {
+        return Ok(KeyCode::Char(char));
+    }
+
+    let lowercase = key.to_lowercase();
+    let name = ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == lowercase)
+        .map_or(lowercase.as_str(), |(_, name)| name);
+
+    if let Some((_, code)) = NAMED_KEYS.iter().find(|(named, _)| *named == name) {
+        return Ok(*code);
+    }
+
+    match name.strip_prefix('f').map(|number| number.parse::<u8>()) {
+        Some(Ok(number)) if (1..=24).contains(&number) => Ok(KeyCode::F(number)),
+        _ => Err(format!("Unknown key: {}", key).into()),
     }
 }
 
-fn key_modifier_from_string(key: &str) -> KeyModifiers {
-    match key {
-        "ctrl" => KeyModifiers::CONTROL,
-        "alt" => KeyModifiers::ALT,
-        "shift" => KeyModifiers::SHIFT,
-        "super" => KeyModifiers::SUPER,
-        _ => panic!("key modifiers not supported: {}", key),
+fn key_modifier_from_string(key: &str) -> Result<KeyModifiers, Error> {
+    match key.to_lowercase().as_str() {
+        "ctrl" | "control" | "c" => Ok(KeyModifiers::CONTROL),
+        "alt" | "meta" | "a" | "m" => Ok(KeyModifiers::ALT),
+        "shift" | "s" => Ok(KeyModifiers::SHIFT),
+        "super" | "cmd" | "d" => Ok(KeyModifiers::SUPER),
+        _ => Err(format!("Unknown modifier: {}", key).into()),
     }
 }
 
-pub fn from_string(key: String) -> (KeyModifiers, KeyCode) {
-    let splitted_key: Vec<&str> = key.split("+").collect();
+fn split_key(key: &str, separator: char) -> (Vec<&str>, &str) {
+    let (modifiers, code) = match key.strip_suffix(separator) {
+        Some(rest) if rest.is_empty() || rest.ends_with(separator) => (
+            rest.strip_suffix(separator).unwrap_or(rest),
+            &key[rest.len()..],
+        ),
+        _ => match key.rsplit_once(separator) {
+            Some((modifiers, code)) => (modifiers, code),
+            None => ("", key),
+        },
+    };
+
+    let modifiers = modifiers
+        .split(separator)
+        .filter(|modifier| !modifier.is_empty())
+        .collect();
 
+    (modifiers, code)
+}
+
+fn key_from_parts(modifiers: Vec<&str>, code: &str) -> Result<Key, Error> {
     let mut key_modifiers = KeyModifiers::NONE;
-    let mut key_code = KeyCode::Null;
+    for modifier in modifiers {
+        key_modifiers |= key_modifier_from_string(modifier)?;
+    }
+
+    let key_code = match key_code_from_string(code)? {
+        KeyCode::Tab if key_modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+        KeyCode::Char(char)
+            if key_modifiers.contains(KeyModifiers::SHIFT) && !char.is_alphabetic() =>
+        {
+            return Err(format!(
+                "Shift cannot be combined with '{}', bind the shifted character instead",
+                char
+            )
+            .into());
+        }
+        KeyCode::Char(char) if key_modifiers.contains(KeyModifiers::SHIFT) => {
+            KeyCode::Char(char.to_ascii_uppercase())
+        }
+        KeyCode::Char(char) if char.is_ascii_uppercase() => {
+            key_modifiers |= KeyModifiers::SHIFT;
+            KeyCode::Char(char)
+        }
+        key_code => key_code,
+    };
+
+    Ok((key_modifiers, key_code))
+}
+
+pub fn parse(key: &str) -> Result<Key, Error> {
+    match key.strip_prefix('<').and_then(|key| key.strip_suffix('>')) {
+        Some(inner) if !inner.is_empty() => {
+            let (modifiers, code) = split_key(inner, '-');
+            key_from_parts(modifiers, code)
+        }
+        _ => {
+            let (modifiers, code) = split_key(key, '+');
+            key_from_parts(modifiers, code)
+        }
+    }
+}
+
+pub fn parse_sequence(keys: &str) -> Result<Vec<Key>, Error> {
+    let mut sequence: Vec<Key> = Vec::new();
+
+    for token in keys.split_whitespace() {
+        if !token.starts_with('<') || token.len() == 1 {
+            sequence.push(parse(token)?);
+            continue;
+        }
+
+        let mut rest = token;
+        while !rest.is_empty() {
+            let end = match rest.strip_prefix('<').and_then(|inner| inner.find('>')) {
+                Some(index) => index + 2,
+                None => rest.chars().next().map_or(0, char::len_utf8),
+            };
+            sequence.push(parse(&rest[..end])?);
+            rest = &rest[end..];
+        }
+    }
+
+    if sequence.is_empty() {
+        return Err("Empty key sequence".into());
+    }
+
+    Ok(sequence)
+}
+
+// Terminals disagree on reporting shift for symbols, which can only be bound without it
+pub fn normalize((modifiers, code): Key) -> Key {
+    match code {
+        KeyCode::Char(char) if !char.is_alphabetic() => (modifiers - KeyModifiers::SHIFT, code),
+        _ => (modifiers, code),
+    }
+}
+
+pub fn from_string(key: String) -> Key {
+    parse(&key).unwrap_or((KeyModifiers::NONE, KeyCode::Null))
+}
+
+pub fn sequence_from_string(keys: String) -> Vec<Key> {
+    parse_sequence(&keys).unwrap_or_default()
+}
+
+pub fn to_string(key: &Key) -> String {
+    let (modifiers, code) = key;
 
-    if splitted_key.len() == 1 {
-        key_code = key_code_from_string(splitted_key[0]);
-    } else if splitted_key.len() == 2 {
-        key_modifiers = key_modifier_from_string(splitted_key[0]);
-        key_code = key_code_from_string(splitted_key[1]);
-    } else {
-        panic!("key not supported: {}", key);
+    let code = match code {
+        KeyCode::Char(' ') => String::from("space"),
+        KeyCode::Char(char) => char.to_string(),
+        KeyCode::F(number) => format!("f{}", number),
+        code => NAMED_KEYS
+            .iter()
+            .find(|(_, named)| named == code)
+            .map_or(format!("{:?}", code).to_lowercase(), |(name, _)| {
+                name.to_string()
+            }),
+    };
+
+    let mut parts: Vec<String> = Vec::new();
+    for (modifier, name) in [
+        (KeyModifiers::CONTROL, "ctrl"),
+        (KeyModifiers::ALT, "alt"),
+        (KeyModifiers::SHIFT, "shift"),
+        (KeyModifiers::SUPER, "super"),
+    ] {
+        if modifiers.contains(modifier) {
+            parts.push(name.to_string());
+        }
     }
+    parts.push(code);
+
+    parts.join("+")
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    use super::{normalize, parse, parse_sequence, to_string};
 
-    (key_modifiers, key_code)
+    const NONE: KeyModifiers = KeyModifiers::NONE;
+    const CTRL: KeyModifiers = KeyModifiers::CONTROL;
+    const ALT: KeyModifiers = KeyModifiers::ALT;
+    const SHIFT: KeyModifiers = KeyModifiers::SHIFT;
+
+    #[test]
+    fn parse_accepts_both_notations() {
+        let cases = [
+            ("j", (NONE, KeyCode::Char('j'))),
+            ("G", (SHIFT, KeyCode::Char('G'))),
+            ("shift+g", (SHIFT, KeyCode::Char('G'))),
+            ("ctrl+k", (CTRL, KeyCode::Char('k'))),
+            ("Control+Alt+x", (CTRL | ALT, KeyCode::Char('x'))),
+            ("ctrl++", (CTRL, KeyCode::Char('+'))),
+            ("+", (NONE, KeyCode::Char('+'))),
+            ("shift+tab", (SHIFT, KeyCode::BackTab)),
+            ("enter", (NONE, KeyCode::Enter)),
+            ("Return", (NONE, KeyCode::Enter)),
+            ("pgdn", (NONE, KeyCode::PageDown)),
+            ("space", (NONE, KeyCode::Char(' '))),
+            ("f12", (NONE, KeyCode::F(12))),
+            ("<C-k>", (CTRL, KeyCode::Char('k'))),
+            ("<M-CR>", (ALT, KeyCode::Enter)),
+            ("<C-->", (CTRL, KeyCode::Char('-'))),
+            ("<lt>", (NONE, KeyCode::Char('<'))),
+            ("<", (NONE, KeyCode::Char('<'))),
+        ];
+
+        for (key, expected) in cases {
+            assert_eq!(parse(key).ok(), Some(expected), "{}", key);
+        }
+    }
+
+    #[test]
+    fn parse_rejects_invalid_keys() {
+        for key in [
+            "",
+            "hyper+k",
+            "f0",
+            "f25",
+            "enterr",
+            "shift+1",
+            "<S-!>",
+            "shift+space",
+        ] {
+            assert!(parse(key).is_err(), "{}", key);
+        }
+    }
+
+    #[test]
+    fn parse_sequence_splits_tokens() {
+        let cases = [
+            (
+                "g g",
+                vec![(NONE, KeyCode::Char('g')), (NONE, KeyCode::Char('g'))],
+            ),
+            (
+                "<C-w>j",
+                vec![(CTRL, KeyCode::Char('w')), (NONE, KeyCode::Char('j'))],
+            ),
+            (
+                "< g",
+                vec![(NONE, KeyCode::Char('<')), (NONE, KeyCode::Char('g'))],
+            ),
+        ];
+
+        for (keys, expected) in cases {
+            assert_eq!(parse_sequence(keys).ok(), Some(expected), "{}", keys);
+        }
+        for keys in ["", "   ", "<leader> x", "g shift+1"] {
+            assert!(parse_sequence(keys).is_err(), "{}", keys);
+        }
+    }
+
+    #[test]
+    fn to_string_round_trips() {
+        for key in [
+            "j",
+            "ctrl+k",
+            "ctrl+alt+x",
+            "shift+G",
+            "space",
+            "f5",
+            "pagedown",
+            "backtab",
+        ] {
+            let parsed = parse(key).unwrap();
+            assert_eq!(parse(&to_string(&parsed)).ok(), Some(parsed), "{}", key);
+        }
+    }
+
+    #[test]
+    fn normalize_drops_shift_from_symbols() {
+        let cases = [
+            ((SHIFT, KeyCode::Char('$')), (NONE, KeyCode::Char('$'))),
+            (
+                (SHIFT | CTRL, KeyCode::Char('1')),
+                (CTRL, KeyCode::Char('1')),
+            ),
+            ((SHIFT, KeyCode::Char('G')), (SHIFT, KeyCode::Char('G'))),
+            ((SHIFT, KeyCode::BackTab), (SHIFT, KeyCode::BackTab)),
+            ((NONE, KeyCode::Char('^')), (NONE, KeyCode::Char('^'))),
+        ];
+
+        for (key, expected) in cases {
+            assert_eq!(normalize(key), expected, "{:?}", key);
+        }
+    }
 }
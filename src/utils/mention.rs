@@ -0,0 +1,264 @@
+use regex::Regex;
+
+use crate::{entities::slack::messages::Message, states::State};
+
+pub fn encode(text: &str, state: &State) -> String {
+    let users = candidates('@', state);
+    let channels = candidates('#', state);
+
+    let mut result = String::new();
+    let mut rest = text;
+    let mut previous: Option<char> = None;
+
+    while let Some(char) = rest.chars().next() {
+        let boundary = previous.is_none_or(|previous| previous.is_whitespace() || previous == '(');
+
+        let candidates = match char {
+            '@' if boundary => Some(&users),
+            '#' if boundary => Some(&channels),
+            _ => None,
+        };
+
+        if let Some((length, encoded)) =
+            candidates.and_then(|candidates| find(&rest[1..], candidates))
+        {
+            result.push_str(&encoded);
+            rest = &rest[1 + length..];
+            previous = Some('>');
+            continue;
+        }
+
+        match char {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            char => result.push(char),
+        }
+        rest = &rest[char.len_utf8()..];
+        previous = Some(char);
+    }
+
+    result
+}
+
+// Messages from others after `last_read` that mention the user directly
+pub fn count(messages: &[Message], user_id: &str, last_read: &str) -> u32 {
+    let mention = format!("<@{}>", user_id);
+    let labelled = format!("<@{}|", user_id);
+
+    messages
+        .iter()
+        .filter(|message| message.ts.as_str() > last_read)
+        .filter(|message| message.user.as_deref() != Some(user_id))
+        .filter(|message| {
+            message
+                .text
+                .as_ref()
+                .is_some_and(|text| text.contains(&mention) || text.contains(&labelled))
+        })
+        .count() as u32
+}
+
+// Inverse of `encode`, turning Slack markup back into what a user would type
+pub fn decode(text: &str, state: &State) -> String {
+    let re = Regex::new(r"<([^>]+)>").unwrap();
+
+    let mut result = String::new();
+    let mut last = 0;
+
+    for captures in re.captures_iter(text) {
+        let whole = captures.get(0).unwrap();
+        result.push_str(&unescape(&text[last..whole.start()]));
+        last = whole.end();
+
+        let (target, label) = match captures[1].split_once('|') {
+            Some((target, label)) => (target.to_string(), Some(label.to_string())),
+            None => (captures[1].to_string(), None),
+        };
+
+        let decoded = if let Some(user_id) = target.strip_prefix('@') {
+            let name = state.global.get_user(user_id.to_string()).map(|member| {
+                if member.profile.display_name.is_empty() {
+                    member.name
+                } else {
+                    member.profile.display_name
+                }
+            });
+            format!("@{}", name.or(label).unwrap_or(user_id.to_string()))
+        } else if let Some(channel_id) = target.strip_prefix('#') {
+            let name = state
+                .channel
+                .get_channel(channel_id.to_string())
+                .and_then(|channel| channel.name);
+            format!("#{}", name.or(label).unwrap_or(channel_id.to_string()))
+        } else if let Some(special) = target.strip_prefix('!') {
+            match special.split_once('^') {
+                Some((_, id)) => format!(
+                    "@{}",
+                    state
+                        .global
+                        .get_usergroup(id.to_string())
+                        .map_or(id.to_string(), |usergroup| usergroup.handle)
+                ),
+                None => format!("@{}", special),
+            }
+        } else {
+            unescape(&target)
+        };
+
+        result.push_str(&decoded);
+    }
+
+    result.push_str(&unescape(&text[last..]));
+
+    result
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+fn candidates(sigil: char, state: &State) -> Vec<(String, String)> {
+    let mut candidates: Vec<(String, String)> = Vec::new();
+
+    if sigil == '#' {
+        for channel in state.channel.channels.iter() {
+            if let Some(name) = channel.name.clone() {
+                candidates.push((name, format!("<#{}>", channel.id)));
+            }
+        }
+        return candidates;
+    }
+
+    for special in ["here", "channel", "everyone"] {
+        candidates.push((special.to_string(), format!("<!{}>", special)));
+    }
+
+    for usergroup in state.global.usergroups.iter() {
+        candidates.push((
+            usergroup.handle.clone(),
+            format!("<!subteam^{}>", usergroup.id),
+        ));
+    }
+
+    for member in state.global.members.iter().filter(|member| !member.deleted) {
+        if !member.profile.display_name.is_empty() {
+            candidates.push((
+                member.profile.display_name.clone(),
+                format!("<@{}>", member.id),
+            ));
+        }
+        candidates.push((member.name.clone(), format!("<@{}>", member.id)));
+    }
+
+    candidates
+}
+
+fn find(text: &str, candidates: &[(String, String)]) -> Option<(usize, String)> {
+    candidates
+        .iter()
+        .filter(|(name, _)| !name.is_empty())
+        .filter(|(name, _)| {
+            text.get(..name.len())
+                .is_some_and(|prefix| prefix.to_lowercase() == name.to_lowercase())
+        })
+        .filter(|(name, _)| {
+            text[name.len()..]
+                .chars()
+                .next()
+                .is_none_or(|next| !(next.is_alphanumeric() || next == '_' || next == '-'))
+        })
+        .max_by_key(|(name, _)| name.len())
+        .map(|(name, encoded)| (name.len(), encoded.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{entities::slack::usergroups::Usergroup, states::State};
+
+    use crate::entities::slack::messages::Message;
+
+    use super::{count, decode, encode};
+
+    #[test]
+    fn encode_escapes_text_between_mentions() {
+        let mut state = State::new();
+        state.global.usergroups.push(Usergroup {
+            id: String::from("S1"),
+            team_id: None,
+            name: String::from("R&D"),
+            handle: String::from("r&d"),
+            description: None,
+        });
+
+        let cases = [
+            ("a < b & c > d", "a &lt; b &amp; c &gt; d"),
+            ("@here <3", "<!here> &lt;3"),
+            ("ping @r&d & @R&D", "ping <!subteam^S1> &amp; <!subteam^S1>"),
+            ("mail@r&d", "mail@r&amp;d"),
+        ];
+
+        for (text, expected) in cases {
+            assert_eq!(encode(text, &state), expected, "{}", text);
+        }
+    }
+
+    #[test]
+    fn decode_reverses_encode() {
+        let mut state = State::new();
+        state.global.usergroups.push(Usergroup {
+            id: String::from("S1"),
+            team_id: None,
+            name: String::from("Ops"),
+            handle: String::from("ops"),
+            description: None,
+        });
+
+        let cases = [
+            ("a &lt; b &amp;lt; c", "a < b &lt; c"),
+            ("<!here> hi <!subteam^S1>", "@here hi @ops"),
+            ("<@U1|ann> and <@U2>", "@ann and @U2"),
+            ("<#C1|general>", "#general"),
+            (
+                "see <https://a.b/?x=1&amp;y=2|docs>",
+                "see https://a.b/?x=1&y=2",
+            ),
+        ];
+
+        for (text, expected) in cases {
+            assert_eq!(decode(text, &state), expected, "{}", text);
+        }
+
+        for text in ["@here & <you>", "#missing @ops"] {
+            assert_eq!(decode(&encode(text, &state), &state), text, "{}", text);
+        }
+    }
+
+    #[test]
+    fn count_only_unread_direct_mentions() {
+        let message = |ts: &str, user: &str, text: &str| {
+            serde_json::from_value::<Message>(serde_json::json!({
+                "type": "message",
+                "ts": ts,
+                "user": user,
+                "text": text,
+            }))
+            .unwrap()
+        };
+
+        let messages = [
+            message("1.000100", "U2", "<@U1> read already"),
+            message("2.000100", "U2", "hey <@U1>"),
+            message("3.000100", "U2", "<@U1|me> labelled"),
+            message("4.000100", "U1", "talking about <@U1>"),
+            message("5.000100", "U2", "<@U10> is someone else"),
+            message("6.000100", "U2", "<!here> is not direct"),
+        ];
+
+        assert_eq!(count(&messages, "U1", "1.000100"), 2);
+        assert_eq!(count(&messages, "U1", "2.000100"), 1);
+        assert_eq!(count(&messages, "U1", "6.000100"), 0);
+    }
+}
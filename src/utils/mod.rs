@@ -1,2 +1,10 @@
+pub mod browser;
+pub mod datetime;
+pub mod editor;
+pub mod emoji;
+pub mod fuzzy;
 pub mod keycode;
-pub mod string;
+pub mod mention;
+pub mod scope;
+pub mod sequence;
+pub mod wrap;
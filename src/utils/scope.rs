@@ -0,0 +1,17 @@
+pub fn granted(scope: &str) -> Vec<String> {
+    scope
+        .split(',')
+        .map(|scope| scope.trim().to_string())
+        .filter(|scope| !scope.is_empty())
+        .collect()
+}
+
+pub fn missing(scope: &str, required: &[String]) -> Vec<String> {
+    let granted = granted(scope);
+
+    required
+        .iter()
+        .filter(|scope| !granted.contains(scope))
+        .cloned()
+        .collect()
+}
@@ -0,0 +1,297 @@
+use std::time::{Duration, Instant};
+
+use crossterm::event::{self, KeyCode, KeyEvent, KeyModifiers};
+use regex::Regex;
+
+use crate::{context::Context, entities::configuration::Configuration, enums::user_mode::UserMode};
+
+use super::keycode;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Binding {
+    pub keys: Vec<(KeyModifiers, KeyCode)>,
+    pub command: String,
+}
+
+pub enum Step {
+    Command(String),
+    Consumed,
+    Unmatched(Option<usize>),
+}
+
+enum Matched {
+    Command(String),
+    Pending,
+    None,
+}
+
+impl Binding {
+    pub fn new(keys: String, command: &str) -> Binding {
+        Binding {
+            keys: keycode::sequence_from_string(keys),
+            command: command.to_string(),
+        }
+    }
+}
+
+pub fn bindings(config: &Configuration, context: &Context) -> Vec<Binding> {
+    let scopes = [
+        config.bindings.widgets.get(&context.focus_id),
+        config.bindings.routes.get(context.current_route()),
+        Some(&config.bindings.global),
+    ];
+
+    let mut bindings: Vec<Binding> = Vec::new();
+
+    for scope in scopes.into_iter().flatten() {
+        for (keys, command) in scope {
+            let binding = Binding::new(keys.clone(), command);
+            if !bindings
+                .iter()
+                .any(|existing| existing.keys == binding.keys)
+            {
+                bindings.push(binding);
+            }
+        }
+    }
+
+    bindings
+}
+
+pub fn step(event: &event::Event, context: &mut Context, bindings: &[Binding]) -> Step {
+    let sequence = &mut context.state.sequence;
+
+    let event::Event::Key(KeyEvent {
+        modifiers, code, ..
+    }) = event
+    else {
+        return Step::Unmatched(None);
+    };
+    let (modifiers, code) = keycode::normalize((*modifiers, *code));
+
+    let normal = context.mode == UserMode::Normal;
+    let bindings: Vec<Binding> = bindings
+        .iter()
+        .filter(|binding| normal || is_chord(binding))
+        .cloned()
+        .collect();
+
+    if let (KeyModifiers::NONE, KeyCode::Char(char)) = (modifiers, code) {
+        if let Some(digit) = char.to_digit(10) {
+            if normal && sequence.pending.is_empty() && (digit != 0 || sequence.count.is_some()) {
+                let count = sequence.count.unwrap_or(0);
+                sequence.count = Some(count.saturating_mul(10).saturating_add(digit as usize));
+                sequence.since = Some(Instant::now());
+                return Step::Consumed;
+            }
+        }
+    }
+
+    sequence.pending.push((modifiers, code));
+
+    match matched(&bindings, &sequence.pending) {
+        Matched::Command(command) if command.is_empty() => {
+            sequence.reset();
+            Step::Consumed
+        }
+        Matched::Command(command) => {
+            let command = with_count(&command, sequence.count);
+            sequence.reset();
+            Step::Command(command)
+        }
+        Matched::Pending => {
+            sequence.since = Some(Instant::now());
+            Step::Consumed
+        }
+        Matched::None if sequence.pending.len() == 1 => {
+            let count = sequence.count;
+            sequence.reset();
+            Step::Unmatched(count)
+        }
+        Matched::None => {
+            sequence.reset();
+            Step::Consumed
+        }
+    }
+}
+
+pub fn expire(
+    config: &Configuration,
+    context: &mut Context,
+    bindings: &[Binding],
+) -> Option<String> {
+    let sequence = &mut context.state.sequence;
+    let since = sequence.since?;
+
+    if since.elapsed() < Duration::from_millis(config.keymaps.timeout) {
+        return None;
+    }
+
+    let command = bindings
+        .iter()
+        .find(|binding| !sequence.pending.is_empty() && binding.keys == sequence.pending)
+        .filter(|binding| !binding.command.is_empty())
+        .map(|binding| with_count(&binding.command, sequence.count));
+    sequence.reset();
+
+    command
+}
+
+pub fn continuations(
+    bindings: &[Binding],
+    pending: &[(KeyModifiers, KeyCode)],
+) -> Vec<(String, String)> {
+    let mut continuations: Vec<(String, String)> = Vec::new();
+
+    for binding in bindings {
+        if binding.command.is_empty()
+            || binding.keys.len() <= pending.len()
+            || !binding.keys.starts_with(pending)
+        {
+            continue;
+        }
+
+        let keys = binding.keys[pending.len()..]
+            .iter()
+            .map(keycode::to_string)
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        if !continuations.iter().any(|(existing, _)| existing == &keys) {
+            continuations.push((keys, binding.command.clone()));
+        }
+    }
+
+    continuations
+}
+
+pub fn with_count(command: &str, count: Option<usize>) -> String {
+    let Some(count) = count else {
+        return command.to_string();
+    };
+
+    let number_re = Regex::new(r"^(.*) (\d+)$").unwrap();
+
+    match number_re.captures(command) {
+        Some(captures) => {
+            let value: usize = captures[2].parse().unwrap_or(1);
+            format!("{} {}", &captures[1], value.saturating_mul(count))
+        }
+        None => command.to_string(),
+    }
+}
+
+fn is_chord(binding: &Binding) -> bool {
+    binding.keys.first().is_some_and(|(modifiers, _)| {
+        modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SUPER)
+    })
+}
+
+fn matched(bindings: &[Binding], pending: &[(KeyModifiers, KeyCode)]) -> Matched {
+    if bindings
+        .iter()
+        .any(|binding| binding.keys.len() > pending.len() && binding.keys.starts_with(pending))
+    {
+        return Matched::Pending;
+    }
+
+    match bindings.iter().find(|binding| binding.keys == pending) {
+        Some(binding) => Matched::Command(binding.command.clone()),
+        None => Matched::None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+
+    use crate::{context::Context, enums::user_mode::UserMode};
+
+    use super::{step, with_count, Binding, Step};
+
+    fn result(step: Step) -> String {
+        match step {
+            Step::Command(command) => command,
+            Step::Consumed => String::from("<consumed>"),
+            Step::Unmatched(count) => format!("<unmatched {:?}>", count),
+        }
+    }
+
+    fn press(keys: &str, mode: UserMode) -> Vec<String> {
+        let bindings = [
+            Binding::new(String::from("g g"), "select first"),
+            Binding::new(String::from("g x"), "open links"),
+            Binding::new(String::from("j"), "select next 1"),
+            Binding::new(String::from("d"), ""),
+            Binding::new(String::from("ctrl+k"), "open switcher"),
+        ];
+        let mut context = Context {
+            mode,
+            ..Context::default()
+        };
+
+        keys.split_whitespace()
+            .map(|key| {
+                let mut chars = key.chars();
+                let (modifiers, code) = match (chars.next(), chars.next()) {
+                    (Some('^'), Some(char)) => (KeyModifiers::CONTROL, KeyCode::Char(char)),
+                    (Some(char), _) => (KeyModifiers::NONE, KeyCode::Char(char)),
+                    _ => unreachable!(),
+                };
+                let event = Event::Key(KeyEvent::new(code, modifiers));
+                result(step(&event, &mut context, &bindings))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn step_resolves_sequences_and_counts() {
+        let cases: [(&str, UserMode, &[&str]); 9] = [
+            ("j", UserMode::Normal, &["select next 1"]),
+            ("g g", UserMode::Normal, &["<consumed>", "select first"]),
+            ("g x", UserMode::Normal, &["<consumed>", "open links"]),
+            ("g z", UserMode::Normal, &["<consumed>", "<consumed>"]),
+            ("q", UserMode::Normal, &["<unmatched None>"]),
+            ("d", UserMode::Normal, &["<consumed>"]),
+            (
+                "1 2 j",
+                UserMode::Normal,
+                &["<consumed>", "<consumed>", "select next 12"],
+            ),
+            (
+                "3 q",
+                UserMode::Normal,
+                &["<consumed>", "<unmatched Some(3)>"],
+            ),
+            (
+                "j 0 ^k",
+                UserMode::Search,
+                &["<unmatched None>", "<unmatched None>", "open switcher"],
+            ),
+        ];
+
+        for (keys, mode, expected) in cases {
+            assert_eq!(press(keys, mode), expected, "{}", keys);
+        }
+    }
+
+    #[test]
+    fn with_count_multiplies_the_trailing_number() {
+        let cases = [
+            ("select next 1", None, "select next 1"),
+            ("select next 1", Some(5), "select next 5"),
+            ("scroll down 3", Some(4), "scroll down 12"),
+            ("select first", Some(2), "select first"),
+        ];
+
+        for (command, count, expected) in cases {
+            assert_eq!(
+                with_count(command, count),
+                expected,
+                "{} {:?}",
+                command,
+                count
+            );
+        }
+    }
+}
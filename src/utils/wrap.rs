@@ -0,0 +1,322 @@
+use std::cmp::max;
+
+use ratatui::{
+    style::Style,
+    text::{Line, Span},
+};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+#[derive(PartialEq)]
+enum Kind {
+    Word,
+    Space,
+    Newline,
+}
+
+struct Token {
+    kind: Kind,
+    wide: bool,
+    graphemes: Vec<(String, Style)>,
+}
+
+struct Lines {
+    lines: Vec<Vec<Span<'static>>>,
+    width: usize,
+    soft: bool,
+}
+
+pub fn width(text: &str) -> usize {
+    UnicodeWidthStr::width(text)
+}
+
+pub fn wrap_spans(spans: Vec<Span<'static>>, width: usize) -> Vec<Vec<Span<'static>>> {
+    let width = max(width, 1);
+    let mut lines = Lines {
+        lines: vec![Vec::new()],
+        width: 0,
+        soft: false,
+    };
+
+    for token in tokens(spans) {
+        let token_width: usize = token
+            .graphemes
+            .iter()
+            .map(|(grapheme, _)| self::width(grapheme))
+            .sum();
+
+        match token.kind {
+            Kind::Newline => lines.break_line(false),
+            Kind::Space => {
+                if lines.width == 0 && lines.soft {
+                    continue;
+                }
+                if lines.width + token_width > width {
+                    lines.break_line(true);
+                    continue;
+                }
+                lines.push(token.graphemes);
+            }
+            Kind::Word => {
+                if lines.width + token_width <= width {
+                    lines.push(token.graphemes);
+                    continue;
+                }
+
+                if lines.width > 0 {
+                    lines.break_line(true);
+                }
+
+                if token_width <= width {
+                    lines.push(token.graphemes);
+                    continue;
+                }
+
+                for grapheme in token.graphemes {
+                    let grapheme_width = self::width(&grapheme.0);
+                    if lines.width > 0 && lines.width + grapheme_width > width {
+                        lines.break_line(true);
+                    }
+                    lines.push(vec![grapheme]);
+                }
+            }
+        }
+    }
+
+    lines.lines
+}
+
+// Row and column of a byte offset of `text` within its wrapped `lines`
+pub fn locate(lines: &[Line], text: &str, offset: usize) -> (usize, usize) {
+    let mut graphemes = text.grapheme_indices(true);
+    let mut position = (0, 0);
+
+    for (row, line) in lines.iter().enumerate() {
+        let mut column = 0;
+
+        for span in line.spans.iter() {
+            for grapheme in span.content.graphemes(true) {
+                for (index, next) in graphemes.by_ref() {
+                    if next == grapheme {
+                        if index >= offset {
+                            return (row, column);
+                        }
+                        break;
+                    }
+                    if index >= offset {
+                        return position;
+                    }
+                    if next == "\n" || next == "\r\n" {
+                        position = (position.0 + 1, 0);
+                    }
+                }
+
+                column += width(grapheme);
+                position = (row, column);
+            }
+        }
+    }
+
+    for (index, next) in graphemes {
+        if index >= offset {
+            break;
+        }
+        if next == "\n" || next == "\r\n" {
+            position = (position.0 + 1, 0);
+        }
+    }
+
+    position
+}
+
+impl Lines {
+    fn break_line(&mut self, soft: bool) {
+        self.lines.push(Vec::new());
+        self.width = 0;
+        self.soft = soft;
+    }
+
+    fn push(&mut self, graphemes: Vec<(String, Style)>) {
+        let line = self.lines.last_mut().unwrap();
+
+        for (grapheme, style) in graphemes {
+            self.width += width(&grapheme);
+
+            match line.last_mut() {
+                Some(last) if last.style == style => {
+                    last.content = format!("{}{}", last.content, grapheme).into();
+                }
+                _ => line.push(Span::styled(grapheme, style)),
+            }
+        }
+    }
+}
+
+fn tokens(spans: Vec<Span<'static>>) -> Vec<Token> {
+    let mut tokens: Vec<Token> = Vec::new();
+
+    for span in spans {
+        for grapheme in span.content.graphemes(true) {
+            let kind = if grapheme == "\n" || grapheme == "\r\n" {
+                Kind::Newline
+            } else if grapheme.chars().all(char::is_whitespace) {
+                Kind::Space
+            } else {
+                Kind::Word
+            };
+
+            let wide = kind == Kind::Word && width(grapheme) > 1;
+
+            match tokens.last_mut() {
+                Some(token)
+                    if token.kind == kind && kind != Kind::Newline && !wide && !token.wide =>
+                {
+                    token.graphemes.push((grapheme.to_string(), span.style));
+                }
+                _ => tokens.push(Token {
+                    kind,
+                    wide,
+                    graphemes: vec![(grapheme.to_string(), span.style)],
+                }),
+            }
+        }
+    }
+
+    tokens
+}
+
+pub fn truncate_spans(
+    spans: Vec<Span<'static>>,
+    width: usize,
+    from_start: bool,
+) -> Vec<Span<'static>> {
+    let total: usize = spans.iter().map(|span| span.width()).sum();
+    if total <= width {
+        return spans;
+    }
+
+    let mut spans = spans;
+    if from_start {
+        spans.reverse();
+    }
+
+    let mut result: Vec<Span<'static>> = Vec::new();
+    let mut remaining = width.saturating_sub(1);
+
+    for span in spans {
+        let mut graphemes: Vec<&str> = span.content.graphemes(true).collect();
+        if from_start {
+            graphemes.reverse();
+        }
+
+        let mut kept: Vec<&str> = Vec::new();
+        let mut cut = false;
+
+        for grapheme in graphemes {
+            let grapheme_width = self::width(grapheme);
+            if grapheme_width > remaining {
+                cut = true;
+                break;
+            }
+            remaining -= grapheme_width;
+            kept.push(grapheme);
+        }
+
+        if from_start {
+            kept.reverse();
+        }
+
+        let content = kept.concat();
+
+        result.push(Span::styled(content, span.style));
+
+        if cut {
+            result.push(Span::styled("…", span.style));
+            break;
+        }
+    }
+
+    if from_start {
+        result.reverse();
+    }
+
+    result
+        .into_iter()
+        .filter(|span| !span.content.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::text::{Line, Span};
+
+    use super::{locate, truncate_spans, wrap_spans};
+
+    fn wrap(text: &str, width: usize) -> Vec<String> {
+        wrap_spans(vec![Span::raw(text.to_string())], width)
+            .into_iter()
+            .map(|line| line.iter().map(|span| span.content.as_ref()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn wrap_spans_breaks_on_words() {
+        let cases: [(&str, usize, Vec<&str>); 8] = [
+            ("", 10, vec![""]),
+            ("hello world", 20, vec!["hello world"]),
+            ("hello world", 8, vec!["hello ", "world"]),
+            ("hello   world", 5, vec!["hello", "world"]),
+            ("abcdefghij", 4, vec!["abcd", "efgh", "ij"]),
+            ("one\ntwo", 10, vec!["one", "two"]),
+            ("a\n\nb", 10, vec!["a", "", "b"]),
+            ("日本語テキスト", 6, vec!["日本語", "テキス", "ト"]),
+        ];
+
+        for (text, width, expected) in cases {
+            assert_eq!(wrap(text, width), expected, "{:?} at {}", text, width);
+        }
+    }
+
+    #[test]
+    fn truncate_spans_adds_an_ellipsis() {
+        let cases: [(&str, usize, bool, &str); 4] = [
+            ("general", 10, false, "general"),
+            ("general", 7, false, "general"),
+            ("general", 5, false, "gene…"),
+            ("general", 5, true, "…eral"),
+        ];
+
+        for (text, width, from_start, expected) in cases {
+            let spans = truncate_spans(vec![Span::raw(text.to_string())], width, from_start);
+            let truncated: String = spans.iter().map(|span| span.content.as_ref()).collect();
+            assert_eq!(truncated, expected, "{:?} at {}", text, width);
+        }
+    }
+
+    #[test]
+    fn locate_follows_the_wrapped_text() {
+        let cases: [(&str, usize, usize, (usize, usize)); 7] = [
+            ("hello", 10, 0, (0, 0)),
+            ("hello", 10, 5, (0, 5)),
+            ("hello world", 8, 6, (1, 0)),
+            ("hello world", 8, 8, (1, 2)),
+            ("aa bbbb", 5, 4, (1, 1)),
+            ("a\n\nb", 10, 2, (1, 0)),
+            ("a\n", 10, 2, (1, 0)),
+        ];
+
+        for (text, width, offset, expected) in cases {
+            let lines: Vec<Line> = wrap_spans(vec![Span::raw(text.to_string())], width)
+                .into_iter()
+                .map(Line::from)
+                .collect();
+            assert_eq!(
+                locate(&lines, text, offset),
+                expected,
+                "{:?} at {}",
+                text,
+                offset
+            );
+        }
+    }
+}